@@ -12,17 +12,16 @@ fn main() {
     env_logger::init();
     let sys = actix::System::new("hello-world");
 
-    let mut s = server::new(|| {
+    let spec: ift::BindSpec = "GetPrivateInterfaces:8080".parse().unwrap();
+    let s = server::new(|| {
         App::new()
             // enable logger
             .middleware(middleware::Logger::default())
             .resource("/index.html", |r| r.f(|_| "Hello world!"))
             .resource("/", |r| r.f(index))
-    });
-
-    for ip in ift::eval("GetPrivateInterfaces").unwrap().into_iter() {
-        s = s.bind((ip, 8080)).unwrap();
-    }
+    })
+    .bind(spec)
+    .unwrap();
 
     println!("Started http server");
     for addr in &s.addrs() {
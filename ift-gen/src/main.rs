@@ -1,182 +1,366 @@
-//! Used to generate the rfc table for use in ift
+//! Used to generate the rfc tables for use in ift
 //!
-use ift::rfc::Rfc6890Entry;
+//! `ift-gen rfc 6890` builds the special-purpose table from the IANA special-purpose
+//! address registries (`iana-ipv4-special-registry.xml` and `iana-ipv6-special-registry.xml`),
+//! which are vendored next to this file. `make download` refreshes them.
+//!
+//! `ift-gen rfc 4291` and `ift-gen rfc 5771` build the address scope tables from
+//! `rfc4291_scopes.csv` and `rfc5771_blocks.csv`.
+//!
+use ipnet::IpNet;
+use roxmltree::{Document, Node};
+use std::{collections::HashMap, env, net::IpAddr, process};
+
+mod scopes;
+
+const IPV4_REGISTRY: &str = include_str!("iana-ipv4-special-registry.xml");
+const IPV6_REGISTRY: &str = include_str!("iana-ipv6-special-registry.xml");
+
+const HEADER: &str = "\
+// generated by ift-gen from the IANA special-purpose address registries. do not edit.
+use crate::rfc::{Date, Rfc6890Entry};
 use ipnet::IpNet;
-use regex::Regex;
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+pub static ENTRIES: &[Rfc6890Entry] = &[
+";
+
+const FOOTER: &str = "];\n";
+
+/// Tags of a record that can carry footnotes, and the Rfc6890Entry attribute they fill
+const NOTED_ATTRIBUTES: &[(&str, &str)] = &[
+    ("address", "address_block"),
+    ("source", "source"),
+    ("destination", "destination"),
+    ("forwardable", "forwardable"),
+    ("global", "global"),
+    ("reserved", "reserved_by_protocol"),
+];
+
+/// One row of an IANA special-purpose address registry
+#[derive(Debug)]
+struct Record {
+    address_block: IpNet,
+    name: String,
+    rfc: String,
+    allocation_date: String,
+    terminated: Option<(u16, u8)>,
+    source: bool,
+    destination: bool,
+    forwardable: bool,
+    global: bool,
+    reserved_by_protocol: bool,
+    notes: Vec<String>,
+}
 
 fn main() {
-    let info = parse_tables(include_str!("rfc6890_entries.txt"));
-    for r in info {
-        if r.termination_date != "N/A" {
-            println!(r"/*{},*/", as_code(&r));
-        } else {
-            println!("{},", as_code(&r));
+    let name = env::args().nth(2).unwrap_or_else(|| "6890".to_owned());
+    match name.as_str() {
+        "6890" => print!("{}", generate_rfc6890()),
+        "4291" => print!("{}", scopes::generate_rfc4291()),
+        "5771" => print!("{}", scopes::generate_rfc5771()),
+        _ => {
+            eprintln!("unknown rfc [{}]", name);
+            process::exit(1);
         }
     }
 }
 
+fn generate_rfc6890() -> String {
+    let mut out = HEADER.to_owned();
+    for registry in &[IPV4_REGISTRY, IPV6_REGISTRY] {
+        for r in parse_registry(registry) {
+            out.push_str(&indent(&format!("{},", as_code(&r))));
+        }
+    }
+    out.push_str(FOOTER);
+    out
+}
+
+fn indent(code: &str) -> String {
+    code.lines().map(|line| format!("    {}\n", line)).collect()
+}
+
 fn escape_quotes(s: &str) -> String {
     s.replace('"', r#"\""#)
 }
 
-fn as_code(entry: &Rfc6890Entry) -> String {
+fn address_block_as_code(address_block: &IpNet) -> String {
+    let addr = match address_block.addr() {
+        IpAddr::V4(ip) => {
+            let o = ip.octets();
+            format!("IpAddr::V4(Ipv4Addr::new({}, {}, {}, {}))", o[0], o[1], o[2], o[3])
+        }
+        IpAddr::V6(ip) => {
+            let segments: Vec<String> = ip.segments().iter().map(|s| format!("{:#x}", s)).collect();
+            format!("IpAddr::V6(Ipv6Addr::new({}))", segments.join(", "))
+        }
+    };
+    format!("IpNet::new_assert({}, {})", addr, address_block.prefix_len())
+}
+
+fn terminated_as_code(terminated: Option<(u16, u8)>) -> String {
+    match terminated {
+        Some((year, month)) => format!("Some(Date {{ year: {}, month: {} }})", year, month),
+        None => "None".to_owned(),
+    }
+}
+
+/// A `&'static str` for a `Cow<'static, str>` field
+fn str_as_code(s: &str) -> String {
+    format!("Cow::Borrowed(\"{}\")", escape_quotes(s))
+}
+
+fn notes_as_code(notes: &[String]) -> String {
+    let notes: Vec<String> = notes.iter().map(|n| str_as_code(n)).collect();
+    format!("Cow::Borrowed(&[{}])", notes.join(", "))
+}
+
+fn as_code(entry: &Record) -> String {
     format!(
         "\
 Rfc6890Entry {{
-    address_block: \"{}\".parse().unwrap(),
-    name: \"{}\".to_owned(),
-    rfc: \"{}\".to_owned(),
-    allocation_date: \"{}\".to_owned(),
-    termination_date: \"{}\".to_owned(),
+    address_block: {},
+    name: {},
+    rfc: {},
+    allocation_date: {},
+    terminated: {},
     source: {},
     destination: {},
     forwardable: {},
     global: {},
-    reserved_by_protocol: {}
+    reserved_by_protocol: {},
+    notes: {},
 }}",
-        entry.address_block,
-        escape_quotes(&entry.name),
-        escape_quotes(&entry.rfc),
-        escape_quotes(&entry.allocation_date),
-        escape_quotes(&entry.termination_date),
+        address_block_as_code(&entry.address_block),
+        str_as_code(&entry.name),
+        str_as_code(&entry.rfc),
+        str_as_code(&entry.allocation_date),
+        terminated_as_code(entry.terminated),
         entry.source,
         entry.destination,
         entry.forwardable,
         entry.global,
-        entry.reserved_by_protocol
+        entry.reserved_by_protocol,
+        notes_as_code(&entry.notes)
     )
 }
 
-fn parse_tables(tables: &str) -> Vec<Rfc6890Entry> {
-    let re = Regex::new(r"(?m)\+----+\+----+\+[^|]+\|\s*Attribute\s*\|\s*Value\s*\|[\r\n]+([^+]+\+----+\+----+\+)[\r\n]+([^+]+)\+----+\+----+\+").unwrap();
+fn parse_registry(xml: &str) -> Vec<Record> {
+    let doc = Document::parse(xml).unwrap_or_else(|e| panic!("unable to parse registry xml. {}", e));
+    let footnotes: HashMap<&str, String> = doc
+        .descendants()
+        .filter(|n| n.has_tag_name("footnote"))
+        .map(|n| (n.attribute("anchor").unwrap_or(""), render_text(&n)))
+        .collect();
+    doc.descendants()
+        .filter(|n| n.has_tag_name("record"))
+        .flat_map(|n| parse_record(&n, &footnotes))
+        .collect()
+}
 
-    re.captures_iter(tables)
-        .map(|cap| {
-            let head = &cap[1];
-            let table = &cap[2];
-            parse_table(head, table)
+/// A record can list more than one address block (e.g. `192.0.0.170/32, 192.0.0.171/32`),
+/// each of which becomes its own entry.
+fn parse_record(record: &Node<'_, '_>, footnotes: &HashMap<&str, String>) -> Vec<Record> {
+    let address = remove_footnote(&child_text(record, "address")).to_owned();
+    let notes = parse_notes(record, footnotes);
+    address
+        .split(',')
+        .map(|block| Record {
+            address_block: parse_address_block(block),
+            name: child_text(record, "name"),
+            rfc: child_text(record, "spec"),
+            allocation_date: child_text(record, "allocation"),
+            terminated: parse_date(&child_text(record, "termination")),
+            source: parse_bool(&child_text(record, "source")),
+            destination: parse_bool(&child_text(record, "destination")),
+            forwardable: parse_bool(&child_text(record, "forwardable")),
+            global: parse_bool(&child_text(record, "global")),
+            reserved_by_protocol: parse_bool(&child_text(record, "reserved")),
+            notes: notes.clone(),
         })
         .collect()
 }
 
-fn parse_table(head: &str, table: &str) -> Rfc6890Entry {
-    let v: Vec<usize> = head.match_indices('+').map(|tup| tup.0).collect();
-    assert_eq!(3, v.len(), "expected that the regex only matched 3 '+' signs");
-
-    let mut output = HashMap::new();
-    for row in table.split('\n') {
-        if !row.trim().is_empty() {
-            assert_eq!(
-                v[2] + 1,
-                row.len(),
-                "expected that each row contains the correct number. [{}], [{}]",
-                row,
-                table
-            );
-
-            let k = row[v[0] + 1..v[1] - 1].trim();
-            let v = row[v[1] + 1..v[2] - 1].trim();
-            output.insert(k.to_owned(), v.to_owned());
+/// Collect the footnotes referenced from a record. Each note is prefixed with the
+/// attributes that reference it, so `True [1]` on forwardable and global becomes
+/// `forwardable, global: <footnote 1>`.
+fn parse_notes(record: &Node<'_, '_>, footnotes: &HashMap<&str, String>) -> Vec<String> {
+    let mut by_anchor: Vec<(&str, Vec<&str>)> = vec![];
+    for (tag, attribute) in NOTED_ATTRIBUTES {
+        let anchors = record
+            .children()
+            .filter(|n| n.has_tag_name(*tag))
+            .flat_map(|n| n.children())
+            .filter(|n| n.has_tag_name("xref") && n.attribute("type") == Some("note"))
+            .filter_map(|n| n.attribute("data"));
+        for anchor in anchors {
+            match by_anchor.iter_mut().find(|(a, _)| *a == anchor) {
+                Some((_, attributes)) => attributes.push(attribute),
+                None => by_anchor.push((anchor, vec![attribute])),
+            }
         }
     }
+    by_anchor
+        .into_iter()
+        .map(|(anchor, attributes)| {
+            let text = footnotes
+                .get(anchor)
+                .unwrap_or_else(|| panic!("missing footnote [{}]", anchor));
+            format!("{}: {}", attributes.join(", "), text)
+        })
+        .collect()
+}
+
+/// Parse the `YYYY-MM` dates used by the registries. `N/A` means there is no date
+fn parse_date(v: &str) -> Option<(u16, u8)> {
+    if v == "N/A" {
+        return None;
+    }
+    let mut parts = v.split('-');
+    match (parts.next(), parts.next()) {
+        (Some(year), Some(month)) => Some((
+            year.parse()
+                .unwrap_or_else(|_| panic!("unable to parse [{}] as a date", v)),
+            month
+                .parse()
+                .unwrap_or_else(|_| panic!("unable to parse [{}] as a date", v)),
+        )),
+        _ => panic!("unable to parse [{}] as a date", v),
+    }
+}
+
+fn child_text(node: &Node<'_, '_>, tag: &str) -> String {
+    node.children()
+        .find(|n| n.has_tag_name(tag))
+        .map(|n| render_text(&n))
+        .unwrap_or_default()
+}
 
-    Rfc6890Entry {
-        address_block: parse_address_block(&output, "Address Block"),
-        name: output["Name"].to_owned(),
-        rfc: output["RFC"].to_owned(),
-        allocation_date: output["Allocation Date"].to_owned(),
-        termination_date: output["Termination Date"].to_owned(),
-        source: parse_bool(&output, "Source"),
-        destination: parse_bool(&output, "Destination"),
-        forwardable: parse_bool(&output, "Forwardable"),
-        global: parse_bool(&output, "Global"),
-        reserved_by_protocol: parse_bool(&output, "Reserved-by-Protocol"),
+/// Flatten the text of an element, rendering `xref`s the way they show up in the
+/// registry tables. `<xref type="rfc" data="rfc1918"/>` becomes `[RFC1918]` and
+/// `<xref type="note" data="1"/>` becomes the footnote marker ` [1]`.
+fn render_text(node: &Node<'_, '_>) -> String {
+    let mut out = String::new();
+    for child in node.children() {
+        if child.is_text() {
+            out.push_str(child.text().unwrap_or(""));
+        } else if child.has_tag_name("xref") {
+            let data = child.attribute("data").unwrap_or("");
+            match child.attribute("type") {
+                Some("rfc") => out.push_str(&format!("[{}]", data.to_uppercase())),
+                Some("rfc-errata") => out.push_str(&format!("[RFC Errata {}]", data)),
+                Some("note") => out.push_str(&format!(" [{}]", data)),
+                _ => out.push_str(data),
+            }
+        } else {
+            out.push_str(&render_text(&child));
+        }
     }
+    out.split_whitespace().collect::<Vec<&str>>().join(" ")
 }
 
-fn remove_footnote<'a, 'b>(map: &'a HashMap<String, String>, key: &'b str) -> &'a str {
-    let v = &map[key];
+fn remove_footnote(v: &str) -> &str {
     v.split('[')
         .next()
-        .unwrap_or_else(|| panic!("split should not return nil. using key[{}]", key))
+        .unwrap_or_else(|| panic!("split should not return nil. using value[{}]", v))
         .trim()
 }
 
-fn parse_address_block(map: &HashMap<String, String>, key: &str) -> IpNet {
-    let v = remove_footnote(map, key);
+fn parse_address_block(v: &str) -> IpNet {
+    let v = v.trim();
     v.to_lowercase()
         .parse()
         .unwrap_or_else(|_| panic!("unable to parse [{}] as ip net", v))
 }
 
-fn parse_bool(map: &HashMap<String, String>, key: &str) -> bool {
-    let v = remove_footnote(map, key).to_lowercase();
+fn parse_bool(v: &str) -> bool {
+    let v = remove_footnote(v).to_lowercase();
     match v.as_str() {
-        "n/a" => false,
+        // terminated entries leave their attributes empty
+        "n/a" | "" => false,
         _ => v.parse().unwrap_or_else(|_| panic!("unable to parse [{}] as bool", v)),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_table, parse_tables};
+    use crate::{generate_rfc6890, parse_registry};
 
     #[test]
-    fn test_parse_table() {
-        let head = "                     +----------------------+----------------------+";
-        let table = "                     | Address Block        | 100.64.0.0/10        |
-                     | Name                 | Shared Address Space |
-                     | RFC                  | [RFC6598]            |
-                     | Allocation Date      | April 2012           |
-                     | Termination Date     | N/A                  |
-                     | Source               | True                 |
-                     | Destination          | True                 |
-                     | Forwardable          | True                 |
-                     | Global               | False                |
-                     | Reserved-by-Protocol | False                |";
-        let r = parse_table(head, table);
+    fn test_parse_registry() {
+        let xml = r#"<?xml version='1.0' encoding='UTF-8'?>
+<registry xmlns="http://www.iana.org/assignments" id="iana-ipv4-special-registry">
+  <registry id="iana-ipv4-special-registry-1">
+    <record>
+      <address>10.0.0.0/8</address>
+      <name>Private-Use</name>
+      <spec><xref type="rfc" data="rfc1918"/></spec>
+      <allocation>1996-02</allocation>
+      <termination>N/A</termination>
+      <source>True</source>
+      <destination>True</destination>
+      <forwardable>True<xref type="note" data="1"/></forwardable>
+      <global>N/A</global>
+      <reserved>False</reserved>
+    </record>
+    <record>
+      <address>192.0.0.170/32, 192.0.0.171/32</address>
+      <name>NAT64/DNS64 Discovery</name>
+      <spec><xref type="rfc" data="rfc8880"/><xref type="rfc" data="rfc7050"/>, Section 2.2</spec>
+      <allocation>2013-02</allocation>
+      <termination>N/A</termination>
+      <source>False</source>
+      <destination>False</destination>
+      <forwardable>False</forwardable>
+      <global>False</global>
+      <reserved>True</reserved>
+    </record>
+    <footnote anchor="1">A footnote.</footnote>
+  </registry>
+</registry>"#;
+        let out = parse_registry(xml);
+        assert_eq!(3, out.len());
+        let r = &out[0];
         assert!(r.forwardable);
+        assert!(!r.global);
+        assert_eq!("[RFC1918]", r.rfc);
+        assert_eq!(vec!["forwardable: A footnote."], r.notes);
+        assert_eq!(None, r.terminated);
+        assert_eq!("192.0.0.171/32", out[2].address_block.to_string());
+        assert_eq!("[RFC8880][RFC7050], Section 2.2", out[2].rfc);
+    }
+
+    #[test]
+    fn test_parse_terminated() {
+        let xml = r#"<registry xmlns="http://www.iana.org/assignments">
+    <record>
+      <address>2001:10::/28</address>
+      <name>Deprecated (previously ORCHID)</name>
+      <spec><xref type="rfc" data="rfc4843"/></spec>
+      <allocation>2007-03</allocation>
+      <termination>2014-03</termination>
+      <source/>
+      <destination/>
+      <forwardable/>
+      <global/>
+      <reserved/>
+    </record>
+</registry>"#;
+        let out = parse_registry(xml);
+        assert_eq!(Some((2014, 3)), out[0].terminated);
+        assert!(!out[0].forwardable);
+        assert!(out[0].notes.is_empty());
     }
 
     #[test]
-    fn test_parse_tables() {
-        let tables = "
-
-              +----------------------+----------------------------+
-              | Attribute            | Value                      |
-              +----------------------+----------------------------+
-              | Address Block        | 0.0.0.0/8                  |
-              | Name                 | \"This host on this network\"|
-              | RFC                  | [RFC1122], Section 3.2.1.3 |
-              | Allocation Date      | September 1981             |
-              | Termination Date     | N/A                        |
-              | Source               | True                       |
-              | Destination          | False                      |
-              | Forwardable          | False                      |
-              | Global               | False                      |
-              | Reserved-by-Protocol | True                       |
-              +----------------------+----------------------------+
-
-                    +----------------------+---------------+
-                    | Attribute            | Value         |
-                    +----------------------+---------------+
-                    | Address Block        | 10.0.0.0/8 [2]|
-                    | Name                 | Private-Use   |
-                    | RFC                  | [RFC1918]     |
-                    | Allocation Date      | February 1996 |
-                    | Termination Date     | N/A           |
-                    | Source               | True          |
-                    | Destination          | True          |
-                    | Forwardable          | True [1]      |
-                    | Global               | N/A           |
-                    | Reserved-by-Protocol | False         |
-                    +----------------------+---------------+
-
-        ";
-        let out = parse_tables(tables);
-        assert_eq!(2, out.len());
-        let r = &out[1];
-        assert_eq!(false, r.global);
+    fn test_generated_is_up_to_date() {
+        assert!(
+            generate_rfc6890() == include_str!("../../src/rfc/rfc6890_entries.rs"),
+            "src/rfc/rfc6890_entries.rs is out of date with the registries. run `make gen-rfc-6890`"
+        );
     }
 }
@@ -0,0 +1,197 @@
+//! Generates the address scope tables, `rfc4291_entries.rs` and `rfc5771_entries.rs`
+//!
+use crate::{address_block_as_code, indent, str_as_code};
+use ipnet::{IpNet, Ipv4Subnets, Ipv6Net};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+const RFC4291_SCOPES: &str = include_str!("rfc4291_scopes.csv");
+const RFC5771_BLOCKS: &str = include_str!("rfc5771_blocks.csv");
+
+/// One address block and the scope it has
+#[derive(Debug)]
+pub struct ScopeRecord {
+    pub address_block: IpNet,
+    pub name: String,
+    pub rfc: String,
+    pub scope: &'static str,
+}
+
+pub fn generate_rfc4291() -> String {
+    generate(
+        "rfc4291_scopes.csv",
+        "Rfc4291Entry",
+        "use std::net::{IpAddr, Ipv6Addr};",
+        &parse_rfc4291(RFC4291_SCOPES),
+    )
+}
+
+pub fn generate_rfc5771() -> String {
+    generate(
+        "rfc5771_blocks.csv",
+        "Rfc5771Entry",
+        "use std::net::{IpAddr, Ipv4Addr};",
+        &parse_rfc5771(RFC5771_BLOCKS),
+    )
+}
+
+fn generate(source: &str, entry_type: &str, net_imports: &str, records: &[ScopeRecord]) -> String {
+    let mut out = format!(
+        "\
+// generated by ift-gen from {}. do not edit.
+use crate::rfc::{{{}, Scope}};
+use ipnet::IpNet;
+use std::borrow::Cow;
+{}
+
+pub static ENTRIES: &[{}] = &[
+",
+        source, entry_type, net_imports, entry_type
+    );
+    for r in records {
+        out.push_str(&indent(&format!(
+            "\
+{} {{
+    address_block: {},
+    name: {},
+    rfc: {},
+    scope: Scope::{},
+}},",
+            entry_type,
+            address_block_as_code(&r.address_block),
+            str_as_code(&r.name),
+            str_as_code(&r.rfc),
+            r.scope
+        )));
+    }
+    out.push_str(crate::FOOTER);
+    out
+}
+
+/// Rows of one of the hand maintained csv files, skipping comments and blank lines.
+/// The last column keeps any extra commas.
+fn rows(csv: &str, columns: usize) -> impl Iterator<Item = Vec<&str>> {
+    csv.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(move |line| {
+            let row: Vec<&str> = line.splitn(columns, ',').map(str::trim).collect();
+            assert_eq!(columns, row.len(), "expected {} columns in [{}]", columns, line);
+            row
+        })
+}
+
+/// Name of the `ift::rfc::Scope` variant for a scope name used in the csv files
+fn scope_variant(scope: &str) -> &'static str {
+    match scope {
+        "interface-local" => "InterfaceLocal",
+        "link-local" => "LinkLocal",
+        "realm-local" => "RealmLocal",
+        "admin-local" => "AdminLocal",
+        "site-local" => "SiteLocal",
+        "organization-local" => "OrganizationLocal",
+        "global" => "Global",
+        _ => panic!("unknown scope [{}]", scope),
+    }
+}
+
+pub fn parse_rfc4291(csv: &str) -> Vec<ScopeRecord> {
+    let mut out = vec![];
+    for row in rows(csv, 4) {
+        let scope = scope_variant(row[1]);
+        if let Ok(scop) = u16::from_str_radix(row[0], 16) {
+            // multicast addresses are ff<flags><scop>::/16, so every flags value gets an entry
+            for flags in 0..16 {
+                let first = 0xff00 | flags << 4 | scop;
+                let net = Ipv6Net::new(Ipv6Addr::new(first, 0, 0, 0, 0, 0, 0, 0), 16).unwrap();
+                out.push(ScopeRecord {
+                    address_block: IpNet::V6(net),
+                    name: row[3].to_owned(),
+                    rfc: row[2].to_owned(),
+                    scope,
+                });
+            }
+        } else {
+            out.push(ScopeRecord {
+                address_block: row[0]
+                    .parse()
+                    .unwrap_or_else(|_| panic!("unable to parse [{}] as ip net", row[0])),
+                name: row[3].to_owned(),
+                rfc: row[2].to_owned(),
+                scope,
+            });
+        }
+    }
+    out
+}
+
+pub fn parse_rfc5771(csv: &str) -> Vec<ScopeRecord> {
+    let mut out = vec![];
+    for row in rows(csv, 5) {
+        let first: Ipv4Addr = row[0]
+            .parse()
+            .unwrap_or_else(|_| panic!("unable to parse [{}] as ip", row[0]));
+        let last: Ipv4Addr = row[1]
+            .parse()
+            .unwrap_or_else(|_| panic!("unable to parse [{}] as ip", row[1]));
+        // ranges like 224.0.2.0 - 224.0.255.255 are not a single cidr block
+        for net in Ipv4Subnets::new(first, last, 0) {
+            out.push(ScopeRecord {
+                address_block: IpNet::V4(net),
+                name: row[4].to_owned(),
+                rfc: row[3].to_owned(),
+                scope: scope_variant(row[2]),
+            });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scopes::{generate_rfc4291, generate_rfc5771, parse_rfc4291, parse_rfc5771};
+
+    #[test]
+    fn test_parse_rfc4291() {
+        let csv = "\
+# comment
+fe80::/10,link-local,[RFC4291],Link-Local Unicast
+5,site-local,[RFC4291],Site-Local Multicast
+";
+        let out = parse_rfc4291(csv);
+        assert_eq!(17, out.len());
+        assert_eq!("LinkLocal", out[0].scope);
+        assert_eq!("ff05::/16", out[1].address_block.to_string());
+        assert_eq!("fff5::/16", out[16].address_block.to_string());
+    }
+
+    #[test]
+    fn test_parse_rfc5771() {
+        let csv = "224.0.2.0,224.0.255.255,global,[RFC5771],AD-HOC Block I";
+        let out = parse_rfc5771(csv);
+        let nets: Vec<String> = out.iter().map(|r| r.address_block.to_string()).collect();
+        assert_eq!(
+            vec![
+                "224.0.2.0/23",
+                "224.0.4.0/22",
+                "224.0.8.0/21",
+                "224.0.16.0/20",
+                "224.0.32.0/19",
+                "224.0.64.0/18",
+                "224.0.128.0/17"
+            ],
+            nets
+        );
+    }
+
+    #[test]
+    fn test_generated_is_up_to_date() {
+        assert!(
+            generate_rfc4291() == include_str!("../../src/rfc/rfc4291_entries.rs"),
+            "src/rfc/rfc4291_entries.rs is out of date. run `make gen-rfc-4291`"
+        );
+        assert!(
+            generate_rfc5771() == include_str!("../../src/rfc/rfc5771_entries.rs"),
+            "src/rfc/rfc5771_entries.rs is out of date. run `make gen-rfc-5771`"
+        );
+    }
+}
@@ -1,11 +1,86 @@
-use clap::{crate_authors, crate_version, App, AppSettings, SubCommand};
-use failure::{bail, Error};
-use ift::{eval, rfc::WithRfc6890};
+use clap::{crate_authors, crate_version, value_t, App, AppSettings, Arg, ArgGroup, ArgMatches, SubCommand};
+use ift::{
+    default_interface_name, eval_detailed, eval_traced, interfaces, lint, render,
+    rfc::{self, Rfc6890Entry, WithRfc4291, WithRfc5771, WithRfc6890},
+    wait_until_detailed, IfTError, Interface, IpInterface, Severity, Template, TraceStage,
+};
+use ipnet::IpNet;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::{
+    env, error, fmt, fs,
+    io::{self, Read, Write},
+    net::IpAddr,
+    process::Command,
+    time::Duration,
+};
+
+/// Any other error
+const EXIT_ERROR: i32 = 1;
+/// The template could not be parsed or evaluated
+const EXIT_TEMPLATE_ERROR: i32 = 2;
+/// Fewer matches than required by --require or --exactly
+const EXIT_NO_MATCH: i32 = 3;
+/// More matches than allowed by --at-most or --exactly
+const EXIT_TOO_MANY_MATCHES: i32 = 4;
+
+type Error = Box<dyn error::Error>;
+
+macro_rules! bail {
+    ($($arg:tt)*) => {
+        return Err(format!($($arg)*).into())
+    };
+}
+
+/// Errors that exit with their own code, so scripts can tell them apart
+#[derive(Debug)]
+enum CliError {
+    Template(IfTError),
+    NoMatch(usize, usize),
+    TooManyMatches(usize, usize),
+    Env(String, Box<CliError>),
+    Check(usize, usize),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Template(e) => write!(f, "{}", e),
+            CliError::NoMatch(min, count) => write!(f, "expected at least {} matches, found {}", min, count),
+            CliError::TooManyMatches(max, count) => write!(f, "expected at most {} matches, found {}", max, count),
+            CliError::Env(name, e) => write!(f, "{}: {}", name, e),
+            CliError::Check(failed, total) => write!(f, "{} of {} templates have problems", failed, total),
+        }
+    }
+}
+
+impl error::Error for CliError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CliError::Template(e) => Some(e),
+            CliError::Env(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl CliError {
+    fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Template(_) => EXIT_TEMPLATE_ERROR,
+            CliError::NoMatch(..) => EXIT_NO_MATCH,
+            CliError::TooManyMatches(..) => EXIT_TOO_MANY_MATCHES,
+            CliError::Env(_, e) => e.exit_code(),
+            CliError::Check(..) => EXIT_TEMPLATE_ERROR,
+        }
+    }
+}
 
 fn main() {
     if let Err(ref e) = run() {
         eprintln!("error: {}", e);
-        ::std::process::exit(1);
+        let code = e.downcast_ref::<CliError>().map_or(EXIT_ERROR, CliError::exit_code);
+        ::std::process::exit(code);
     }
 }
 
@@ -18,34 +93,969 @@ fn run() -> Result<(), Error> {
         .subcommand(
             SubCommand::with_name("eval")
                 .about("Evaluate an ift template")
-                .args_from_usage("<template> 'Template string to evaluate'"),
+                .after_help(
+                    "TEMPLATE FILES:
+    Templates read with --file, - or --from-env may span multiple lines and have # comments.
+    With --named every line is a name=template pair, and each result is printed with its name.
+
+EXIT CODES:
+    0    success
+    1    any other error
+    2    the template could not be parsed or evaluated
+    3    fewer matches than --require or --exactly
+    4    more matches than --at-most or --exactly",
+                )
+                .args_from_usage(
+                    "[template] 'Template string to evaluate, - to read it from stdin'
+                    -f, --file [FILE] 'Read the template from a file, - for stdin'
+                    --from-env [VAR] 'Read the template from an environment variable'
+                    --named 'Read one name=template per line, and print the results by name'",
+                )
+                .group(
+                    ArgGroup::with_name("source")
+                        .args(&["template", "file", "from-env"])
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("detailed")
+                        .long("detailed")
+                        .help("Print the interface, prefix, flags and rfc classification of each result")
+                        .conflicts_with("named"),
+                )
+                .args(&cardinality_args())
+                .arg(ips_format_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("wait")
+                .about("Evaluate an ift template until it has matches, or --require/--exactly/--at-most are met")
+                .after_help(
+                    "DURATIONS:
+    a number of seconds, or a number followed by ms, s or m. e.g. 500ms, 30s, 2m
+
+EXIT CODES:
+    0    success
+    1    any other error
+    2    the template could not be parsed or evaluated
+    3    fewer matches than --require or --exactly (at least 1 by default) after --timeout
+    4    more matches than --at-most or --exactly after --timeout",
+                )
+                .args_from_usage(
+                    "<template> 'Template string to evaluate'
+                    --timeout [DURATION] 'How long to wait before giving up'
+                    --poll [DURATION] 'How often to evaluate again, even without a change notification'",
+                )
+                .args(&cardinality_args())
+                .arg(ips_format_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("watch")
+                .about("Print the result of an ift template as a line of json, and again every time it changes")
+                .after_help(
+                    "DURATIONS:
+    a number of seconds, or a number followed by ms, s or m. e.g. 500ms, 30s, 2m",
+                )
+                .args_from_usage(
+                    "<template> 'Template string to evaluate'
+                    --poll [DURATION] 'How often to evaluate again, even without a change notification'",
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("render")
+                .about("Replace every {{ template }} in a document with the ips it evaluates to, {{{{ is a literal {{")
+                .args_from_usage(
+                    "-i, --input [FILE] 'Document to render, stdin if not given'
+                    -o, --output [FILE] 'Where to write the rendered document, stdout if not given'",
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("exec")
+                .about("Run a command with template results in its environment and arguments")
+                .after_help(
+                    "Each --env NAME=TEMPLATE is evaluated, exported as NAME, and replaces {{NAME}} in the
+command's arguments. Multiple ips are separated by a space. Every template has to match at
+least once unless --require, --exactly or --at-most say otherwise.
+
+    ift exec --env BIND_ADDR='GetPrivateInterfaces | FilterIPv4 | FilterFirst' -- \\
+        myserver --listen '{{BIND_ADDR}}:8080'
+
+EXIT CODES:
+    1    any other error, including the command not being found
+    2    a template could not be parsed or evaluated
+    3    fewer matches than --require or --exactly
+    4    more matches than --at-most or --exactly
+    otherwise the exit code of the command",
+                )
+                .arg(
+                    Arg::with_name("env")
+                        .long("env")
+                        .short("e")
+                        .value_name("NAME=TEMPLATE")
+                        .help("Evaluate TEMPLATE into the environment variable NAME")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .required(true),
+                )
+                .args(&cardinality_args())
+                .arg(
+                    Arg::with_name("command")
+                        .help("The command to run and its arguments")
+                        .required(true)
+                        .multiple(true)
+                        .last(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("check")
+                .about("Check ift templates for mistakes, without evaluating them")
+                .after_help(
+                    "EXIT CODES:
+    0    no errors, or no warnings either with --strict
+    1    any other error
+    2    a template has errors, or warnings with --strict",
+                )
+                .args_from_usage(
+                    "<template>... 'Template strings to check'
+                    --strict 'Fail on warnings too'",
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("explain")
+                .about("Show what every stage of an ift template did, and why addresses were dropped")
+                .args_from_usage(
+                    "<template> 'Template string to evaluate'
+                    --json 'Print the input, output and dropped addresses of every stage as json'",
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("interfaces")
+                .about("List every interface and address that templates are evaluated against")
+                .args_from_usage("--json 'Print the interfaces as json, with their addresses nested'"),
         )
         .subcommand(
             SubCommand::with_name("rfc")
                 .about("Dump rfc content")
-                .args_from_usage("<name> 'rfc name to dump'"),
+                .setting(AppSettings::SubcommandsNegateReqs)
+                .args_from_usage("<name> 'rfc name to dump. 6890, 4291 or 5771'")
+                .arg(format_arg())
+                .subcommand(
+                    SubCommand::with_name("lookup")
+                        .about("Find the most specific rfc 6890 entry for addresses or cidrs")
+                        .args_from_usage(
+                            "<address>... 'Addresses or cidrs to look up'
+                            --include-terminated 'Also match entries that have been terminated, like the 6to4 relay anycast block'",
+                        )
+                        .arg(format_arg()),
+                ),
         )
         .get_matches();
 
     match matches.subcommand() {
         ("eval", Some(eval_matches)) => {
-            let template = eval_matches.value_of("template").unwrap();
-            let ips: Vec<String> = eval(template)?.into_iter().map(|ip_addr| ip_addr.to_string()).collect();
-
-            println!("[{}]", ips.join(" "));
+            let source = read_template_source(eval_matches)?;
+            let format = eval_matches.value_of("format").unwrap();
+            let (min, max) = cardinality(eval_matches, 0)?;
+            if eval_matches.is_present("named") {
+                let mut results = vec![];
+                for (name, template) in parse_named_templates(&source)? {
+                    let ips = eval_detailed(&template)
+                        .map_err(|e| CliError::Env(name.clone(), Box::new(CliError::Template(e))))?;
+                    check_cardinality(ips.len(), min, max).map_err(|e| CliError::Env(name.clone(), Box::new(e)))?;
+                    results.push((name, ips.iter().map(IpInterface::to_zoned_string).collect()));
+                }
+                print!("{}", format_named_ips(&results, format)?);
+                return Ok(());
+            }
+            let template = join_template_lines(&source);
+            if eval_matches.is_present("detailed") {
+                let ips = eval_detailed(&template).map_err(CliError::Template)?;
+                check_cardinality(ips.len(), min, max)?;
+                let mut table = detailed_table(&ips);
+                if format == "first" {
+                    table.rows.truncate(1);
+                }
+                let table_format = match format {
+                    "json" | "csv" => format,
+                    _ => "table",
+                };
+                print!("{}", table.format(table_format)?);
+            } else {
+                let ips = eval_detailed(&template).map_err(CliError::Template)?;
+                check_cardinality(ips.len(), min, max)?;
+                let ips: Vec<String> = ips.iter().map(IpInterface::to_zoned_string).collect();
+                print!("{}", format_ips(&ips, format)?);
+            }
+            Ok(())
+        }
+        ("wait", Some(wait_matches)) => {
+            let template = wait_matches.value_of("template").unwrap();
+            let timeout = parse_duration(wait_matches.value_of("timeout").unwrap_or("60s"))?;
+            let poll = parse_duration(wait_matches.value_of("poll").unwrap_or("1s"))?;
+            let (min, max) = cardinality(wait_matches, 1)?;
+            let ips = wait_until_detailed(template, timeout, poll, |ips| {
+                check_cardinality(ips.len(), min, max).is_ok()
+            })
+            .map_err(CliError::Template)?;
+            check_cardinality(ips.len(), min, max)?;
+            let ips: Vec<String> = ips.iter().map(IpInterface::to_zoned_string).collect();
+            print!("{}", format_ips(&ips, wait_matches.value_of("format").unwrap())?);
+            Ok(())
+        }
+        ("watch", Some(watch_matches)) => {
+            let template: Template = watch_matches
+                .value_of("template")
+                .unwrap()
+                .parse()
+                .map_err(CliError::Template)?;
+            let poll = parse_duration(watch_matches.value_of("poll").unwrap_or("1s"))?;
+            let stdout = io::stdout();
+            for ips in template.watch_detailed(poll) {
+                let ips: Vec<String> = ips
+                    .map_err(CliError::Template)?
+                    .iter()
+                    .map(IpInterface::to_zoned_string)
+                    .collect();
+                let mut out = stdout.lock();
+                writeln!(out, "{}", serde_json::to_string(&ips)?)?;
+                out.flush()?;
+            }
+            Ok(())
+        }
+        ("render", Some(render_matches)) => {
+            let document = match render_matches.value_of("input") {
+                Some(path) => fs::read_to_string(path)?,
+                None => {
+                    let mut document = String::new();
+                    io::stdin().read_to_string(&mut document)?;
+                    document
+                }
+            };
+            let rendered = render(&document).map_err(CliError::Template)?;
+            match render_matches.value_of("output") {
+                Some(path) => fs::write(path, rendered)?,
+                None => print!("{}", rendered),
+            }
+            Ok(())
+        }
+        ("exec", Some(exec_matches)) => {
+            let (min, max) = cardinality(exec_matches, 1)?;
+            let mut vars = vec![];
+            for env in exec_matches.values_of("env").unwrap() {
+                let (name, template) = match env.find('=') {
+                    Some(i) if i > 0 => (&env[..i], &env[i + 1..]),
+                    _ => bail!("expected NAME=TEMPLATE, got [{}]", env),
+                };
+                let ips = eval_detailed(template)
+                    .map_err(|e| CliError::Env(name.to_owned(), Box::new(CliError::Template(e))))?;
+                check_cardinality(ips.len(), min, max).map_err(|e| CliError::Env(name.to_owned(), Box::new(e)))?;
+                let ips: Vec<String> = ips.iter().map(IpInterface::to_zoned_string).collect();
+                vars.push((name, ips.join(" ")));
+            }
+            let args: Vec<String> = exec_matches
+                .values_of("command")
+                .unwrap()
+                .map(|arg| substitute_placeholders(arg, &vars))
+                .collect();
+            let mut command = Command::new(&args[0]);
+            command.args(&args[1..]).envs(vars);
+            exec(command)
+        }
+        ("check", Some(check_matches)) => {
+            let fail_at = if check_matches.is_present("strict") {
+                Severity::Warning
+            } else {
+                Severity::Error
+            };
+            let templates: Vec<&str> = check_matches.values_of("template").unwrap().collect();
+            let mut failed = 0;
+            for template in &templates {
+                let lints = lint(template);
+                for l in &lints {
+                    print!("{}", l.annotate(template));
+                }
+                if lints.iter().any(|l| l.severity >= fail_at) {
+                    failed += 1;
+                }
+            }
+            if failed > 0 {
+                Err(CliError::Check(failed, templates.len()))?;
+            }
+            Ok(())
+        }
+        ("explain", Some(explain_matches)) => {
+            let trace = eval_traced(explain_matches.value_of("template").unwrap()).map_err(CliError::Template)?;
+            if explain_matches.is_present("json") {
+                println!("{}", serde_json::to_string_pretty(&trace)?);
+            } else {
+                print!("{}", trace_text(&trace));
+            }
+            Ok(())
+        }
+        ("interfaces", Some(interfaces_matches)) => {
+            let default = match default_interface_name() {
+                Ok(default) => default,
+                Err(e) => {
+                    eprintln!("warning: {}", e);
+                    None
+                }
+            };
+            let interfaces = interfaces()?;
+            if interfaces_matches.is_present("json") {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&interfaces_json(&interfaces, &default))?
+                );
+            } else {
+                print!("{}", interfaces_table(&interfaces, &default).to_text());
+            }
             Ok(())
         }
         ("rfc", Some(rfc_matches)) => {
+            if let Some(lookup_matches) = rfc_matches.subcommand_matches("lookup") {
+                let addresses = lookup_matches.values_of("address").unwrap();
+                let rfc = WithRfc6890 {
+                    include_terminated: lookup_matches.is_present("include-terminated"),
+                    ..WithRfc6890::create()
+                };
+                match lookup_matches.value_of("format").unwrap() {
+                    "json" => println!("{}", serde_json::to_string_pretty(&rfc6890_lookup(&rfc, addresses)?)?),
+                    format => print!("{}", rfc6890_lookup_table(&rfc, addresses)?.format(format)?),
+                }
+                return Ok(());
+            }
             let name = rfc_matches.value_of("name").unwrap();
-            let rfc = match name {
-                "6890" => WithRfc6890::create(),
+            let format = rfc_matches.value_of("format").unwrap();
+            if format == "json" {
+                // the entries' own Serialize impls, so the cli and the library agree on the shape
+                let json = match name {
+                    "6890" => serde_json::to_string_pretty(&WithRfc6890::create().entries)?,
+                    "4291" => serde_json::to_string_pretty(&WithRfc4291::create().entries)?,
+                    "5771" => serde_json::to_string_pretty(&WithRfc5771::create().entries)?,
+                    _ => bail!("unknown rfc [{}]", name),
+                };
+                println!("{}", json);
+                return Ok(());
+            }
+            let table = match name {
+                "6890" => rfc6890_table(),
+                "4291" => rfc4291_table(),
+                "5771" => rfc5771_table(),
                 _ => bail!("unknown rfc [{}]", name),
             };
-            for entry in rfc.entries {
-                println!("{:?}", entry)
-            }
+            print!("{}", table.format(format)?);
             Ok(())
         }
         _ => bail!("unknown sub command"),
     }
 }
+
+fn format_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("format")
+        .long("format")
+        .help("Output format")
+        .takes_value(true)
+        .possible_values(&["table", "json", "csv"])
+        .default_value("table")
+}
+
+fn ips_format_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("format")
+        .long("format")
+        .help("Output format")
+        .takes_value(true)
+        .possible_values(&["list", "lines", "json", "csv", "space", "first"])
+        .default_value("list")
+}
+
+fn cardinality_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    vec![
+        Arg::from_usage("--require [N] 'Require at least N matches'"),
+        Arg::from_usage("--at-most [N] 'Allow at most N matches'"),
+        Arg::with_name("exactly")
+            .long("exactly")
+            .value_name("N")
+            .help("Require exactly N matches")
+            .takes_value(true)
+            .conflicts_with_all(&["require", "at-most"]),
+    ]
+}
+
+/// The template as given on the command line, or the contents of --file, stdin or --from-env
+fn read_template_source(matches: &ArgMatches<'_>) -> Result<String, Error> {
+    let path = match (matches.value_of("template"), matches.value_of("file")) {
+        (Some("-"), _) | (_, Some("-")) => {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source)?;
+            return Ok(source);
+        }
+        (Some(template), _) => return Ok(template.to_owned()),
+        (_, Some(path)) => path,
+        _ => {
+            let var = matches.value_of("from-env").unwrap();
+            return match env::var(var) {
+                Ok(source) => Ok(source),
+                Err(e) => bail!("unable to read template from ${}: {}", var, e),
+            };
+        }
+    };
+    match fs::read_to_string(path) {
+        Ok(source) => Ok(source),
+        Err(e) => bail!("unable to read template from {}: {}", path, e),
+    }
+}
+
+/// Lines of a template source without comments or blank lines
+fn template_lines(source: &str) -> impl Iterator<Item = (usize, &str)> {
+    source
+        .lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| (i + 1, line))
+}
+
+/// Templates can be split over multiple lines in files, but not in the grammar
+fn join_template_lines(source: &str) -> String {
+    template_lines(source)
+        .map(|(_, line)| line)
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn parse_named_templates(source: &str) -> Result<Vec<(String, String)>, Error> {
+    let mut templates = vec![];
+    for (number, line) in template_lines(source) {
+        match line.find('=') {
+            Some(i) if !line[..i].trim().is_empty() => {
+                templates.push((line[..i].trim().to_owned(), line[i + 1..].trim().to_owned()))
+            }
+            _ => bail!("line {}: expected name=template, got [{}]", number, line),
+        }
+    }
+    Ok(templates)
+}
+
+/// Minimum and maximum number of matches allowed by --require, --at-most and --exactly,
+/// `default_min` when none of them are given
+fn cardinality(matches: &ArgMatches<'_>, default_min: usize) -> Result<(usize, Option<usize>), Error> {
+    if matches.is_present("exactly") {
+        let exactly = value_t!(matches, "exactly", usize)?;
+        return Ok((exactly, Some(exactly)));
+    }
+    let min = if matches.is_present("require") {
+        value_t!(matches, "require", usize)?
+    } else if matches.is_present("at-most") {
+        0
+    } else {
+        default_min
+    };
+    let max = if matches.is_present("at-most") {
+        Some(value_t!(matches, "at-most", usize)?)
+    } else {
+        None
+    };
+    Ok((min, max))
+}
+
+fn check_cardinality(count: usize, min: usize, max: Option<usize>) -> Result<(), CliError> {
+    if count < min {
+        return Err(CliError::NoMatch(min, count));
+    }
+    match max {
+        Some(max) if count > max => Err(CliError::TooManyMatches(max, count)),
+        _ => Ok(()),
+    }
+}
+
+/// Replace {{NAME}} and {{ NAME }} with the value of NAME
+fn substitute_placeholders(arg: &str, vars: &[(&str, String)]) -> String {
+    let mut arg = arg.to_owned();
+    for (name, value) in vars {
+        arg = arg
+            .replace(&format!("{{{{{}}}}}", name), value)
+            .replace(&format!("{{{{ {} }}}}", name), value);
+    }
+    arg
+}
+
+/// Replace this process with the command
+#[cfg(unix)]
+fn exec(mut command: Command) -> Result<(), Error> {
+    use std::os::unix::process::CommandExt;
+    let err = command.exec();
+    bail!("unable to run {:?}: {}", command, err)
+}
+
+/// Run the command and exit with its exit code, there is no exec outside of unix
+#[cfg(not(unix))]
+fn exec(mut command: Command) -> Result<(), Error> {
+    let status = match command.status() {
+        Ok(status) => status,
+        Err(err) => bail!("unable to run {:?}: {}", command, err),
+    };
+    ::std::process::exit(status.code().unwrap_or(EXIT_ERROR))
+}
+
+/// Parse durations like 500ms, 30s or 2m. Plain numbers are seconds
+fn parse_duration(s: &str) -> Result<Duration, Error> {
+    let (number, unit) = match s.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let number: u64 = match number.parse() {
+        Ok(number) => number,
+        Err(_) => bail!("unable to parse duration [{}]", s),
+    };
+    Ok(match unit {
+        "ms" => Duration::from_millis(number),
+        "s" => Duration::from_secs(number),
+        "m" => match number.checked_mul(60) {
+            Some(secs) => Duration::from_secs(secs),
+            None => bail!("duration [{}] is too long", s),
+        },
+        _ => bail!("unknown unit in duration [{}], use ms, s or m", s),
+    })
+}
+
+fn format_ips(ips: &[String], format: &str) -> Result<String, Error> {
+    Ok(match format {
+        "list" => format!("[{}]\n", ips.join(" ")),
+        "space" => format!("{}\n", ips.join(" ")),
+        "lines" => ips.iter().map(|ip| format!("{}\n", ip)).collect(),
+        "first" => ips.iter().take(1).map(|ip| format!("{}\n", ip)).collect(),
+        "json" => format!("{}\n", serde_json::to_string(ips)?),
+        "csv" => {
+            let mut out = "ip_addr\n".to_owned();
+            for ip in ips {
+                out.push_str(&format!("{}\n", ip));
+            }
+            out
+        }
+        _ => bail!("unknown format [{}]", format),
+    })
+}
+
+/// Like `format_ips`, but every line starts with the name of the template. json is an object
+/// of names to ips and csv gets a name column
+fn format_named_ips(results: &[(String, Vec<String>)], format: &str) -> Result<String, Error> {
+    Ok(match format {
+        "json" => {
+            let mut object = Map::new();
+            for (name, ips) in results {
+                object.insert(name.clone(), json!(ips));
+            }
+            format!("{}\n", serde_json::to_string(&object)?)
+        }
+        "csv" => {
+            let mut out = "name,ip_addr\n".to_owned();
+            for (name, ips) in results {
+                for ip in ips {
+                    out.push_str(&format!("{},{}\n", csv_field(name), ip));
+                }
+            }
+            out
+        }
+        _ => {
+            let mut out = String::new();
+            for (name, ips) in results {
+                for line in format_ips(ips, format)?.lines() {
+                    out.push_str(&format!("{}={}\n", name, line));
+                }
+            }
+            out
+        }
+    })
+}
+
+fn detailed_table(ips: &[IpInterface]) -> Table {
+    let rfc = WithRfc6890::create();
+    Table {
+        columns: vec![
+            "ip_addr",
+            "prefix",
+            "interface",
+            "index",
+            "flags",
+            "scope",
+            "forwardable",
+            "global",
+            "rfc6890",
+        ],
+        rows: ips
+            .iter()
+            .map(|ip| {
+                let mut row = vec![
+                    json!(ip.to_zoned_string()),
+                    json!(ip.prefix),
+                    json!(ip.interface_name),
+                    json!(ip.interface_index),
+                    json!(ip.flags),
+                ];
+                row.extend(classify(&ip.ip_addr, &rfc));
+                row
+            })
+            .collect(),
+    }
+}
+
+/// Every stage with what came out of it, and a line for each address it dropped
+fn trace_text(trace: &[TraceStage]) -> String {
+    let describe = |ip: &IpInterface| match ip.interface_name {
+        Some(ref name) => format!("{} ({})", ip.ip_addr, name),
+        None => ip.ip_addr.to_string(),
+    };
+    let mut out = String::new();
+    for (i, stage) in trace.iter().enumerate() {
+        let pipe = if i == 0 { "" } else { "| " };
+        out.push_str(&format!(
+            "{}{}: {} in, {} out\n",
+            pipe,
+            stage.stage,
+            stage.input.len(),
+            stage.output.len()
+        ));
+        let rule = stage.stage.split_whitespace().next().unwrap_or("");
+        for dropped in &stage.dropped {
+            out.push_str(&format!(
+                "    {} dropped by {}: {}\n",
+                describe(&dropped.ip),
+                rule,
+                dropped.reason
+            ));
+        }
+        let output: Vec<String> = stage.output.iter().map(describe).collect();
+        out.push_str(&format!("    => [{}]\n", output.join(", ")));
+    }
+    out
+}
+
+/// The classification of an address, as shown by `ift eval --detailed` and `ift interfaces`
+fn classify(ip: &IpAddr, rfc: &WithRfc6890) -> Vec<Value> {
+    vec![
+        json!(rfc::scope(ip).name()),
+        json!(rfc.is_forwardable(ip)),
+        json!(rfc.is_global(ip)),
+        json!(rfc.find_most_specific(ip).map(|e| &e.name)),
+    ]
+}
+
+fn interfaces_json(interfaces: &[Interface], default: &Option<String>) -> Value {
+    let rfc = WithRfc6890::create();
+    let columns = ["scope", "forwardable", "global", "rfc6890"];
+    Value::Array(
+        interfaces
+            .iter()
+            .map(|int| {
+                let addresses: Vec<Value> = int
+                    .addresses
+                    .iter()
+                    .map(|net| {
+                        let mut object = Map::new();
+                        object.insert("address".to_owned(), json!(net.addr().to_string()));
+                        object.insert("prefix".to_owned(), json!(net.prefix_len()));
+                        for (column, value) in columns.iter().zip(classify(&net.addr(), &rfc)) {
+                            object.insert((*column).to_owned(), value);
+                        }
+                        Value::Object(object)
+                    })
+                    .collect();
+                json!({
+                    "name": int.name,
+                    "index": int.index,
+                    "flags": int.flags,
+                    "mtu": int.mtu,
+                    "mac": int.mac,
+                    "default": default.as_ref() == Some(&int.name),
+                    "addresses": addresses,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// One row per address, or per interface when it does not have any
+fn interfaces_table(interfaces: &[Interface], default: &Option<String>) -> Table {
+    let rfc = WithRfc6890::create();
+    let mut rows = vec![];
+    for int in interfaces {
+        let interface = vec![
+            json!(int.name),
+            json!(int.index),
+            json!(int.flags),
+            json!(int.mtu),
+            json!(int.mac),
+            json!(default.as_ref() == Some(&int.name)),
+        ];
+        if int.addresses.is_empty() {
+            let mut row = interface.clone();
+            row.extend(vec![Value::Null; 5]);
+            rows.push(row);
+        }
+        for net in &int.addresses {
+            let mut row = interface.clone();
+            row.push(json!(net.to_string()));
+            row.extend(classify(&net.addr(), &rfc));
+            rows.push(row);
+        }
+    }
+    Table {
+        columns: vec![
+            "interface",
+            "index",
+            "flags",
+            "mtu",
+            "mac",
+            "default",
+            "address",
+            "scope",
+            "forwardable",
+            "global",
+            "rfc6890",
+        ],
+        rows,
+    }
+}
+
+/// Rows of json values with named columns, that can be printed in any of the output formats
+struct Table {
+    columns: Vec<&'static str>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    fn format(&self, format: &str) -> Result<String, Error> {
+        match format {
+            "table" => Ok(self.to_text()),
+            "json" => Ok(format!("{}\n", self.to_json()?)),
+            "csv" => Ok(self.to_csv()),
+            _ => bail!("unknown format [{}]", format),
+        }
+    }
+
+    fn to_json(&self) -> Result<String, Error> {
+        let objects: Vec<Value> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut object = Map::new();
+                for (column, value) in self.columns.iter().zip(row) {
+                    object.insert((*column).to_owned(), value.clone());
+                }
+                Value::Object(object)
+            })
+            .collect();
+        Ok(serde_json::to_string_pretty(&objects)?)
+    }
+
+    fn to_csv(&self) -> String {
+        let mut out = format!("{}\n", self.columns.join(","));
+        for row in &self.rows {
+            let fields: Vec<String> = row.iter().map(|v| csv_field(&value_to_string(v))).collect();
+            out.push_str(&format!("{}\n", fields.join(",")));
+        }
+        out
+    }
+
+    fn to_text(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(value_to_string).collect())
+            .collect();
+        let mut widths: Vec<usize> = self.columns.iter().map(|c| c.len()).collect();
+        for row in &rows {
+            for (i, field) in row.iter().enumerate() {
+                widths[i] = widths[i].max(field.chars().count());
+            }
+        }
+        let line = |fields: Vec<&str>| -> String {
+            let padded: Vec<String> = fields
+                .iter()
+                .zip(&widths)
+                .map(|(f, w)| format!("{:width$}", f, width = w))
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        };
+        let mut out = line(self.columns.clone());
+        for row in &rows {
+            out.push_str(&line(row.iter().map(String::as_str).collect()));
+        }
+        out
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => "".to_owned(),
+        Value::String(s) => s.clone(),
+        Value::Array(values) => values.iter().map(value_to_string).collect::<Vec<String>>().join("; "),
+        _ => value.to_string(),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn rfc6890_table() -> Table {
+    Table {
+        columns: vec![
+            "address_block",
+            "name",
+            "rfc",
+            "allocation_date",
+            "terminated",
+            "source",
+            "destination",
+            "forwardable",
+            "global",
+            "reserved_by_protocol",
+            "notes",
+        ],
+        rows: WithRfc6890::create()
+            .entries
+            .iter()
+            .map(|e| {
+                vec![
+                    json!(e.address_block.to_string()),
+                    json!(e.name),
+                    json!(e.rfc),
+                    json!(e.allocation_date),
+                    json!(e.terminated.map(|d| d.to_string())),
+                    json!(e.source),
+                    json!(e.destination),
+                    json!(e.forwardable),
+                    json!(e.global),
+                    json!(e.reserved_by_protocol),
+                    json!(e.notes),
+                ]
+            })
+            .collect(),
+    }
+}
+
+/// An `rfc lookup` result, the matching entry's fields follow the query
+#[derive(Serialize)]
+struct RfcLookup<'a> {
+    query: &'a str,
+    special_purpose: bool,
+    #[serde(flatten)]
+    entry: Option<Rfc6890Entry>,
+}
+
+fn rfc6890_lookup<'a>(
+    rfc: &WithRfc6890,
+    addresses: impl Iterator<Item = &'a str>,
+) -> Result<Vec<RfcLookup<'a>>, Error> {
+    let mut lookups = vec![];
+    for address in addresses {
+        let net: IpNet = match address.parse::<IpAddr>() {
+            Ok(ip) => IpNet::from(ip),
+            Err(_) => match address.parse() {
+                Ok(net) => net,
+                Err(_) => bail!("unable to parse [{}] as an address or cidr", address),
+            },
+        };
+        let entry = rfc.find_most_specific_net(&net).cloned();
+        lookups.push(RfcLookup {
+            query: address,
+            special_purpose: entry.is_some(),
+            entry,
+        });
+    }
+    Ok(lookups)
+}
+
+fn rfc6890_lookup_table<'a>(rfc: &WithRfc6890, addresses: impl Iterator<Item = &'a str>) -> Result<Table, Error> {
+    let mut rows = vec![];
+    for lookup in rfc6890_lookup(rfc, addresses)? {
+        let address = lookup.query;
+        rows.push(match &lookup.entry {
+            Some(e) => vec![
+                json!(address),
+                json!(true),
+                json!(e.address_block.to_string()),
+                json!(e.name),
+                json!(e.rfc),
+                json!(e.terminated.map(|d| d.to_string())),
+                json!(e.source),
+                json!(e.destination),
+                json!(e.forwardable),
+                json!(e.global),
+                json!(e.reserved_by_protocol),
+                json!(e.notes),
+            ],
+            None => vec![
+                json!(address),
+                json!(false),
+                Value::Null,
+                json!("not special-purpose"),
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                json!([]),
+            ],
+        });
+    }
+    Ok(Table {
+        columns: vec![
+            "query",
+            "special_purpose",
+            "address_block",
+            "name",
+            "rfc",
+            "terminated",
+            "source",
+            "destination",
+            "forwardable",
+            "global",
+            "reserved_by_protocol",
+            "notes",
+        ],
+        rows,
+    })
+}
+
+fn rfc4291_table() -> Table {
+    Table {
+        columns: vec!["address_block", "name", "rfc", "scope"],
+        rows: WithRfc4291::create()
+            .entries
+            .iter()
+            .map(|e| {
+                vec![
+                    json!(e.address_block.to_string()),
+                    json!(e.name),
+                    json!(e.rfc),
+                    json!(e.scope.name()),
+                ]
+            })
+            .collect(),
+    }
+}
+
+fn rfc5771_table() -> Table {
+    Table {
+        columns: vec!["address_block", "name", "rfc", "scope"],
+        rows: WithRfc5771::create()
+            .entries
+            .iter()
+            .map(|e| {
+                vec![
+                    json!(e.address_block.to_string()),
+                    json!(e.name),
+                    json!(e.rfc),
+                    json!(e.scope.name()),
+                ]
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,204 @@
+//! A template and a port to bind to, see `BindSpec`
+use crate::{IfTError, Template};
+use std::{
+    fmt, io,
+    net::{SocketAddr, ToSocketAddrs},
+    str::FromStr,
+    vec,
+};
+
+/// # A template with a port, ready to bind to
+///
+/// Parses from `TEMPLATE:PORT`, like `GetPrivateInterfaces | FilterIPv4:8080`, so it can come
+/// straight from a config file or the command line. It is `ToSocketAddrs`, so it can be handed to
+/// anything that binds. Every time it is bound the template is evaluated again.
+///
+/// `TcpListener::bind` and `UdpSocket::bind` only bind the first address that works, servers
+/// like actix-web bind all of them.
+///
+/// ```
+/// use ift::BindSpec;
+/// use std::net::TcpListener;
+/// let spec: BindSpec = r#"GetAllInterfaces | FilterCIDR "127.0.0.0/8":0"#.parse().unwrap();
+/// assert_eq!(0, spec.port());
+/// let listener = TcpListener::bind(&spec).unwrap();
+/// assert!(listener.local_addr().unwrap().ip().is_loopback());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BindSpec {
+    template: Template,
+    port: u16,
+}
+
+impl BindSpec {
+    /// Bind spec for the addresses of `template`, with `port` unless the template has a `WithPort`
+    pub fn new(template: Template, port: u16) -> BindSpec {
+        BindSpec { template, port }
+    }
+
+    /// The template
+    pub fn template(&self) -> &Template {
+        &self.template
+    }
+
+    /// The port
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// See `eval_socket_addrs`
+    pub fn eval(&self) -> Result<Vec<SocketAddr>, IfTError> {
+        self.template.eval_socket_addrs(self.port)
+    }
+}
+
+impl FromStr for BindSpec {
+    type Err = IfTError;
+
+    /// The port is after the last `:`, templates can have `:` in them too, like in
+    /// `FilterCIDR "fe80::/64"`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (template, port) = match s.rfind(':') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => Err(IfTError::IfTBindSpecError(s.to_owned()))?,
+        };
+        let port: u16 = port.parse().map_err(|_| IfTError::IfTBindSpecError(s.to_owned()))?;
+        Ok(BindSpec::new(template.trim_end().parse()?, port))
+    }
+}
+
+impl fmt::Display for BindSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.template, self.port)
+    }
+}
+
+/// With the `serde` feature a bind spec is a `TEMPLATE:PORT` string, and the template has to pass
+/// `Template::parse_strict`
+#[cfg(feature = "serde")]
+impl serde::Serialize for BindSpec {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BindSpec {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let spec = String::deserialize(deserializer)?;
+        spec.parse()
+            .and_then(|bind: BindSpec| Ok(BindSpec::new(bind.template.deny_lint_errors()?, bind.port)))
+            .map_err(|e| serde::de::Error::custom(format!("invalid bind spec [{}]: {}", spec, e)))
+    }
+}
+
+impl ToSocketAddrs for BindSpec {
+    type Iter = vec::IntoIter<SocketAddr>;
+
+    fn to_socket_addrs(&self) -> io::Result<Self::Iter> {
+        self.eval().map(Vec::into_iter).map_err(io::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{eval_socket_addrs, BindSpec, IfTError};
+    use std::net::ToSocketAddrs;
+
+    #[test]
+    fn test_parse_bind_spec() {
+        let spec: BindSpec = "GetPrivateInterfaces | FilterIPv4:8080".parse().unwrap();
+        assert_eq!("GetPrivateInterfaces | FilterIPv4", spec.template().as_str());
+        assert_eq!(8080, spec.port());
+        assert_eq!("GetPrivateInterfaces | FilterIPv4:8080", spec.to_string());
+        let spec: BindSpec = r#"GetAllInterfaces | FilterCIDR "fe80::/64%eth0":443"#.parse().unwrap();
+        assert_eq!(
+            r#"GetAllInterfaces | FilterCIDR "fe80::/64%eth0""#,
+            spec.template().as_str()
+        );
+        assert_eq!(443, spec.port());
+    }
+
+    #[test]
+    fn test_parse_bind_spec_errors() {
+        for s in &[
+            "GetPrivateInterfaces",
+            r#"GetAllInterfaces | FilterCIDR "fe80::/64""#,
+            "GetPrivateInterfaces:http",
+            "GetPrivateInterfaces:65536",
+        ] {
+            match s.parse::<BindSpec>() {
+                Err(IfTError::IfTBindSpecError(_)) => (),
+                other => panic!("expected a bind spec error for {}, got {:?}", s, other),
+            }
+        }
+        match "GetNothing:80".parse::<BindSpec>() {
+            Err(IfTError::Parse(_)) => (),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_config() {
+        use crate::{DefaultRoutePolicy, Template};
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct Config {
+            bind: BindSpec,
+            advertise: Template,
+            default_route: DefaultRoutePolicy,
+        }
+        let config: Config = serde_json::from_str(
+            r#"{"bind": "GetPrivateInterfaces:8080", "advertise": "GetPrivateInterfaces | FilterFirst", "default_route": "ignore"}"#,
+        )
+        .unwrap();
+        assert_eq!(8080, config.bind.port());
+        assert_eq!("GetPrivateInterfaces | FilterFirst", config.advertise.as_str());
+        assert_eq!(DefaultRoutePolicy::Ignore, config.default_route);
+        assert_eq!(
+            r#""GetPrivateInterfaces:8080""#,
+            serde_json::to_string(&config.bind).unwrap()
+        );
+
+        let err = serde_json::from_str::<BindSpec>(r#""GetPrivateInterfaces""#).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid bind spec [GetPrivateInterfaces]: "),
+            "{}",
+            err
+        );
+        let err = serde_json::from_str::<BindSpec>(r#""GetPrivateInterfaces | SortBy:80""#).unwrap_err();
+        assert!(
+            err.to_string().contains("missing quoted argument after `SortBy`"),
+            "{}",
+            err
+        );
+
+        let err = serde_json::from_str::<Template>(r#""GetAllInterfaces | SortBy \"name\"""#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(r#"unknown sort attribute "name", expected "default""#),
+            "{}",
+            err
+        );
+        assert!(r#"GetAllInterfaces | WithPort "99999":80"#.parse::<BindSpec>().is_ok());
+        for bad in &[
+            r#""GetAllInterfaces | FilterFlags \"sideways\":80""#,
+            r#""GetAllInterfaces | FilterScope \"planet\":80""#,
+            r#""GetAllInterfaces | WithPort \"99999\":80""#,
+        ] {
+            serde_json::from_str::<BindSpec>(bad).expect_err(bad);
+        }
+    }
+
+    #[test]
+    fn test_bind_spec_socket_addrs() {
+        let spec: BindSpec = "GetAllInterfaces:8080".parse().unwrap();
+        assert_eq!(
+            eval_socket_addrs("GetAllInterfaces", 8080).unwrap(),
+            spec.to_socket_addrs().unwrap().collect::<Vec<_>>()
+        );
+    }
+}
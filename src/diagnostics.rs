@@ -0,0 +1,332 @@
+//! Turning pest's parse errors into something that talks about producers, filters and sorts
+use crate::grammar::{IfTParser, Rule};
+use pest::{
+    error::{Error, ErrorVariant, InputLocation},
+    iterators::Pair,
+    Parser,
+};
+use std::{error, fmt, ops::Range};
+
+const PRODUCERS: &[&str] = &["GetInterface", "GetAllInterfaces", "GetPrivateInterfaces"];
+const FILTERS_AND_SORTS: &[&str] = &[
+    "FilterIPv4",
+    "FilterIPv6",
+    "FilterFlags",
+    "FilterForwardable",
+    "FilterGlobal",
+    "FilterScope",
+    "FilterName",
+    "FilterCIDR",
+    "FilterFirst",
+    "FilterLast",
+    "SortBy",
+    "WithPort",
+];
+const WITH_ARGUMENT: &[&str] = &[
+    "GetInterface",
+    "FilterFlags",
+    "FilterScope",
+    "FilterName",
+    "FilterCIDR",
+    "SortBy",
+    "WithPort",
+];
+
+/// Why a template does not parse, and where
+///
+/// ```
+/// use ift::{IfTError, Template};
+/// let err = Template::parse("GetAllInterfaces | FilterIpv4").unwrap_err();
+/// match err {
+///     IfTError::Parse(e) => {
+///         assert_eq!("unknown filter `FilterIpv4`; did you mean `FilterIPv4`?", e.message);
+///         assert_eq!(Some("FilterIPv4"), e.suggestion.as_ref().map(String::as_str));
+///         assert_eq!(19..29, e.span);
+///         assert_eq!((1, 20), (e.line, e.column));
+///     }
+///     _ => panic!("expected a parse error"),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What is wrong, including the suggestion if there is one
+    pub message: String,
+    /// What was probably meant, like `FilterIPv4` for `FilterIpv4`
+    pub suggestion: Option<String>,
+    /// Byte range of the template that does not parse
+    pub span: Range<usize>,
+    /// Line the span starts on, starting at 1
+    pub line: usize,
+    /// Column the span starts on, in characters starting at 1
+    pub column: usize,
+}
+
+impl ParseError {
+    fn new(template: &str, message: String, suggestion: Option<&str>, span: Range<usize>) -> ParseError {
+        let (line, column) = line_column(template, span.start);
+        ParseError {
+            message,
+            suggestion: suggestion.map(str::to_owned),
+            span,
+            line,
+            column,
+        }
+    }
+
+    fn from_pest(template: &str, e: Error<Rule>) -> ParseError {
+        let pos = match e.location {
+            InputLocation::Pos(pos) => pos,
+            InputLocation::Span((start, _)) => start,
+        };
+        let expects_stage = match e.variant {
+            ErrorVariant::ParsingError { ref positives, .. } => positives.iter().any(|r| {
+                matches!(
+                    r,
+                    Rule::producer | Rule::filter | Rule::sort | Rule::SortBy | Rule::with | Rule::WithPort
+                )
+            }),
+            ErrorVariant::CustomError { .. } => false,
+        };
+        let is_word = |c: char| c.is_ascii_alphanumeric();
+        let start = template[..pos].rfind(|c| !is_word(c)).map_or(0, |i| i + 1);
+        let end = template[pos..]
+            .find(|c| !is_word(c))
+            .map_or(template.len(), |i| pos + i);
+        let word = &template[start..end];
+        let is_producer = !template[..start].contains('|');
+        let (kind, names) = if is_producer {
+            ("producer", PRODUCERS)
+        } else if word.to_lowercase().starts_with("sort") {
+            ("sort", FILTERS_AND_SORTS)
+        } else if word.to_lowercase().starts_with("with") {
+            ("stage", FILTERS_AND_SORTS)
+        } else {
+            ("filter", FILTERS_AND_SORTS)
+        };
+
+        if word.is_empty() {
+            return match template[pos..].chars().next() {
+                Some(c) => ParseError::new(template, format!("unexpected `{}`", c), None, pos..pos + c.len_utf8()),
+                None if is_producer => ParseError::new(
+                    template,
+                    "expected a producer like `GetAllInterfaces`".to_owned(),
+                    None,
+                    pos..pos,
+                ),
+                None => ParseError::new(
+                    template,
+                    "expected a filter or sort after `|`".to_owned(),
+                    None,
+                    pos..pos,
+                ),
+            };
+        }
+        if !expects_stage && start == pos {
+            return ParseError::new(template, format!("expected `|` before `{}`", word), None, start..end);
+        }
+        if names.contains(&word) && WITH_ARGUMENT.contains(&word) {
+            return argument_error(template, word, end);
+        }
+        let rest = template[end..].trim_start();
+        if let (true, Some(c)) = (names.contains(&word), rest.chars().next()) {
+            let at = template.len() - rest.len();
+            return ParseError::new(
+                template,
+                format!("unexpected `{}` after `{}`", c, word),
+                None,
+                at..at + c.len_utf8(),
+            );
+        }
+        let suggestion = closest(word, names);
+        let message = match suggestion {
+            Some(suggestion) => format!("unknown {} `{}`; did you mean `{}`?", kind, word, suggestion),
+            None => format!("unknown {} `{}`, expected one of {}", kind, word, names.join(", ")),
+        };
+        ParseError::new(template, message, suggestion, start..end)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parse a template into its expression
+pub(crate) fn parse(template: &str) -> Result<Pair<'_, Rule>, ParseError> {
+    match IfTParser::parse(Rule::template, template) {
+        Ok(mut pairs) => pairs.next().ok_or_else(|| {
+            ParseError::new(
+                template,
+                "expected a producer like `GetAllInterfaces`".to_owned(),
+                None,
+                0..0,
+            )
+        }),
+        Err(e) => Err(ParseError::from_pest(template, e)),
+    }
+}
+
+/// The first pair inside of `pair`, like the argument of `FilterName "lo"`
+pub(crate) fn inner<'i>(pair: &Pair<'i, Rule>) -> Result<Pair<'i, Rule>, ParseError> {
+    pair.clone().into_inner().next().ok_or_else(|| unexpected(pair))
+}
+
+/// A pair the grammar produced that evaluation does not know what to do with
+pub(crate) fn unexpected(pair: &Pair<'_, Rule>) -> ParseError {
+    let span = pair.as_span();
+    let (line, column) = span.start_pos().line_col();
+    ParseError {
+        message: format!("unexpected {:?} `{}`", pair.as_rule(), pair.as_str()),
+        suggestion: None,
+        span: span.start()..span.end(),
+        line,
+        column,
+    }
+}
+
+/// Line and column, both starting at 1, of a byte offset
+pub(crate) fn line_column(template: &str, offset: usize) -> (usize, usize) {
+    let before = &template[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// The stage parsed, so it has to be its quoted argument that did not
+fn argument_error(template: &str, stage: &str, stage_end: usize) -> ParseError {
+    let rest = &template[stage_end..];
+    let start = stage_end + (rest.len() - rest.trim_start_matches(' ').len());
+    let rest = &template[start..];
+    if !rest.starts_with('"') {
+        let end = rest.find(' ').map_or(template.len(), |i| start + i);
+        return ParseError::new(
+            template,
+            format!("missing quoted argument after `{}`", stage),
+            None,
+            start..end,
+        );
+    }
+    match rest[1..].find('"') {
+        Some(i) if stage == "FilterCIDR" => ParseError::new(
+            template,
+            "the argument of `FilterCIDR` has to be a network like `10.0.0.0/8` or `fe80::/64%eth0`".to_owned(),
+            None,
+            start..start + i + 2,
+        ),
+        Some(i) => ParseError::new(
+            template,
+            format!(
+                "the argument of `{}` can only have letters, numbers and `-` in it",
+                stage
+            ),
+            None,
+            start..start + i + 2,
+        ),
+        None => ParseError::new(
+            template,
+            format!("missing closing `\"` after the argument of `{}`", stage),
+            None,
+            start..template.len(),
+        ),
+    }
+}
+
+/// The name that is the fewest edits away from `word`, ignoring case, if it is close enough
+fn closest<'a>(word: &str, names: &[&'a str]) -> Option<&'a str> {
+    let word = word.to_lowercase();
+    names
+        .iter()
+        .map(|name| (edit_distance(&word, &name.to_lowercase()), *name))
+        .filter(|(distance, _)| *distance <= 3)
+        .min()
+        .map(|(_, name)| name)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::{parse, ParseError};
+
+    fn error(template: &str) -> ParseError {
+        parse(template).expect_err("should not parse")
+    }
+
+    #[test]
+    fn test_unknown_stages() {
+        let e = error("GetAllInterfaces | FilterIpv4");
+        assert_eq!("unknown filter `FilterIpv4`; did you mean `FilterIPv4`?", e.message);
+        assert_eq!(
+            "unknown producer `GetAllInterface`; did you mean `GetAllInterfaces`?",
+            error("GetAllInterface").message
+        );
+        let e = error("GetAllInterfaces | FilterIPv4x");
+        assert_eq!("unknown filter `FilterIPv4x`; did you mean `FilterIPv4`?", e.message);
+        assert_eq!(19..30, e.span);
+        assert_eq!(None, error("GetAllInterfaces | FilterNothing").suggestion);
+    }
+
+    #[test]
+    fn test_arguments() {
+        let e = error("GetAllInterfaces | FilterName | FilterIPv4");
+        assert_eq!("missing quoted argument after `FilterName`", e.message);
+        assert_eq!(30..31, e.span);
+        assert_eq!(
+            "missing quoted argument after `SortBy`",
+            error("GetAllInterfaces | SortBy default").message
+        );
+        assert_eq!(
+            "missing closing `\"` after the argument of `GetInterface`",
+            error(r#"GetInterface "eth0"#).message
+        );
+        assert_eq!(
+            "the argument of `FilterName` can only have letters, numbers and `-` in it",
+            error(r#"GetAllInterfaces | FilterName "eth0.1""#).message
+        );
+        assert_eq!(
+            "the argument of `FilterCIDR` has to be a network like `10.0.0.0/8` or `fe80::/64%eth0`",
+            error(r#"GetAllInterfaces | FilterCIDR "10.0.0.0/8,""#).message
+        );
+    }
+
+    #[test]
+    fn test_structure() {
+        assert_eq!("expected a producer like `GetAllInterfaces`", error("").message);
+        assert_eq!(
+            "expected a filter or sort after `|`",
+            error("GetAllInterfaces |").message
+        );
+        let e = error("GetAllInterfaces FilterIPv4");
+        assert_eq!("expected `|` before `FilterIPv4`", e.message);
+        assert_eq!((1, 18), (e.line, e.column));
+        assert_eq!("unexpected `|`", error("GetAllInterfaces | | FilterIPv4").message);
+        let e = error("GetAllInterfaces)");
+        assert_eq!("unexpected `)` after `GetAllInterfaces`", e.message);
+        assert_eq!(None, e.suggestion);
+        assert_eq!(16..17, e.span);
+        assert_eq!(
+            "unexpected `;` after `FilterFirst`",
+            error("GetAllInterfaces | FilterFirst;").message
+        );
+        let e = error("GetAllInterfaces | FilterIPv4é");
+        assert_eq!("unexpected `é` after `FilterIPv4`", e.message);
+        assert_eq!(29..31, e.span);
+    }
+}
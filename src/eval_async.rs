@@ -0,0 +1,86 @@
+//! Evaluating templates from async code without blocking the executor, see `eval_async`
+use crate::{IfTError, Template};
+use std::{net::IpAddr, panic};
+
+/// Just like `eval`, but runs on tokio's blocking pool.
+///
+/// Listing the interfaces and looking up the default route block the thread they run on, so
+/// this keeps them off of the executor's worker threads. Needs a tokio runtime and the `async`
+/// feature. Fails with `IfTError::IfTCancelledError` when the runtime shuts down first.
+///
+/// ```
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let ips = ift::eval_async("GetAllInterfaces | FilterIPv4").await.unwrap();
+/// assert_eq!(ift::eval("GetAllInterfaces | FilterIPv4").unwrap(), ips);
+/// # });
+/// ```
+pub async fn eval_async(s: &str) -> Result<Vec<IpAddr>, IfTError> {
+    Template::parse(s)?.eval_async().await
+}
+
+impl Template {
+    /// See `eval_async`
+    pub async fn eval_async(&self) -> Result<Vec<IpAddr>, IfTError> {
+        let template = self.clone();
+        match tokio::task::spawn_blocking(move || template.eval()).await {
+            Ok(ips) => ips,
+            Err(e) if e.is_panic() => panic::resume_unwind(e.into_panic()),
+            Err(e) => Err(IfTError::IfTCancelledError(format!(
+                "the runtime shut down before the template was evaluated: {}",
+                e
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{eval, eval_async, IfTError, Template};
+    use tokio::runtime::{Builder, Runtime};
+
+    fn runtime() -> Runtime {
+        Builder::new_current_thread().build().unwrap()
+    }
+
+    #[test]
+    fn test_eval_async_matches_eval() {
+        let template: Template = r#"GetAllInterfaces | SortBy "default""#.parse().unwrap();
+        let ips = runtime().block_on(template.eval_async()).unwrap();
+        assert_eq!(eval(template.as_str()).unwrap(), ips);
+    }
+
+    #[test]
+    fn test_eval_async_allows_lint_errors_like_eval() {
+        let template = "GetAllInterfaces | FilterIPv4 | FilterIPv6";
+        assert_eq!(
+            eval(template).unwrap(),
+            runtime().block_on(eval_async(template)).unwrap()
+        );
+        assert!(eval(template).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_eval_async_cancelled() {
+        let shut_down = runtime();
+        let handle = shut_down.handle().clone();
+        drop(shut_down);
+        let result = runtime().block_on(async {
+            // the blocking pool that eval_async spawns on is gone, so the task never runs
+            let _guard = handle.enter();
+            eval_async("GetAllInterfaces").await
+        });
+        match result {
+            Err(IfTError::IfTCancelledError(_)) => (),
+            other => panic!("expected the evaluation to be cancelled, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_eval_async_bad_template() {
+        match runtime().block_on(eval_async("GetAllInterfaces | FilterNothing")) {
+            Err(IfTError::Parse(_)) => (),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+}
@@ -56,12 +56,30 @@ fn test_filter_flags_missing() {
     assert_not_rule!(Rule::filter, s);
 }
 
+#[test]
+fn test_filter_scope() {
+    let s = r#"FilterScope "link-local""#;
+    assert_rule!(Rule::filter, s);
+}
+
+#[test]
+fn test_filter_cidr() {
+    assert_rule!(Rule::filter, r#"FilterCIDR "10.0.0.0/8""#);
+    assert_rule!(Rule::filter, r#"FilterCIDR "fe80::/64%eth0""#);
+}
+
 #[test]
 fn test_sort_by() {
     let s = r#"SortBy "default""#;
     assert_rule!(Rule::sort, s);
 }
 
+#[test]
+fn test_with_port() {
+    let s = r#"WithPort "8080""#;
+    assert_rule!(Rule::with, s);
+}
+
 #[test]
 fn test_producer() {
     let s = "GetAllInterfaces";
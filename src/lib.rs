@@ -22,15 +22,24 @@
 //! ```
 //!
 //! ### actix
-//! ```
+//! ```no_run
 //! use actix_web::{
 //!    server,
 //!    App,
 //! };
-//! let mut s = server::new(|| { App::new() });
-//! for ip in ift::eval("GetPrivateInterfaces").unwrap().into_iter() {
-//!   s = s.bind((ip, 8080)).unwrap();
-//! }
+//! let spec: ift::BindSpec = "GetPrivateInterfaces:8080".parse().unwrap();
+//! let s = server::new(|| { App::new() }).bind(spec).unwrap();
+//! ```
+//!
+//! ### waiting for an interface
+//! ```no_run
+//! use std::time::Duration;
+//! let ips = ift::wait_for("GetPrivateInterfaces", Duration::from_secs(30), Duration::from_secs(1));
+//! ```
+//!
+//! ### config files
+//! ```
+//! let config = ift::render(r#"bind_addr = "{{ GetPrivateInterfaces | FilterFirst }}""#).unwrap();
 //! ```
 //!
 //! ### Example Templates
@@ -46,37 +55,109 @@
 //! ### Example Code
 //! There are examples in the [examples](https://github.com/camerondavison/ift/tree/master/examples)
 //! folder.
-//! * [actix](https://github.com/camerondavison/ift/blob/master/examples/actix.rs) - bind multiple private interfaces
+//! * [actix](https://github.com/camerondavison/ift/blob/master/examples/actix.rs) - bind multiple private interfaces with a `BindSpec`
 //!
-use failure::{Error, Fail};
-use pest::{iterators::Pair, Parser};
+use ipnet::IpNet;
+use pest::iterators::Pair;
 use pnet::datalink::{self, NetworkInterface};
-use std::{cmp::Ordering, net::IpAddr, rc::Rc, str::FromStr};
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt, io,
+    net::{IpAddr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
 pub mod rfc;
 mod routes;
-use crate::{rfc::WithRfc6890, routes::read_default_interface_name};
+pub use crate::routes::DefaultRoutePolicy;
+use crate::{
+    rfc::{Scope, WithRfc6890},
+    routes::read_default_interface_name,
+};
 mod grammar;
-use crate::grammar::{IfTParser, Rule};
+use crate::grammar::Rule;
+mod bind;
+pub use crate::bind::BindSpec;
+mod diagnostics;
+pub use crate::diagnostics::ParseError;
+use crate::diagnostics::{inner, parse, unexpected};
+mod lint;
+pub use crate::lint::{lint, Lint, Severity};
+mod render;
+pub use crate::render::render;
+mod trace;
+pub use crate::trace::{DroppedIp, TraceStage};
+mod watch;
+pub use crate::watch::{wait_for, wait_until, wait_until_detailed, Watcher};
+#[cfg(feature = "async")]
+mod eval_async;
+#[cfg(feature = "async")]
+pub use crate::eval_async::eval_async;
 
 /// Some errors that can come from the evaluation of the template
-#[derive(Debug, Fail)]
+#[derive(Debug)]
 pub enum IfTError {
-    /// Error parsing string to utf8
-    #[fail(display = "{}", _0)]
-    Utf8(#[fail(cause)] ::std::string::FromUtf8Error),
-    /// IO error reading template
-    #[fail(display = "{}", _0)]
-    Io(#[fail(cause)] ::std::io::Error),
-    /// Pest parse error
-    #[fail(display = "{}", _0)]
-    Pest(::pest::error::Error<Rule>),
+    /// The template does not parse
+    Parse(ParseError),
     /// Error parsing a flag
-    #[fail(display = "unable to parse flag {}", _0)]
     IfTFlagError(String),
     /// Error parsing an argument
-    #[fail(display = "unable to use argument {}", _0)]
     IfTArgumentError(String),
+    /// The interface that the default route goes through could not be looked up
+    IfTRouteError(io::Error),
+    /// The network interfaces could not be listed
+    IfTInterfaceError(String),
+    /// A `BindSpec` that is not `TEMPLATE:PORT`
+    IfTBindSpecError(String),
+    /// The template did not produce what was waited for in time
+    IfTTimeoutError(Duration),
+    /// The template parses, but `lint` found an error in it, see `Template::parse_strict`
+    IfTLintError(Lint),
+    /// The evaluation was cancelled before it finished, like when `eval_async`'s runtime shuts down
+    IfTCancelledError(String),
+    /// Error rendering the templates inside of a document
+    IfTRenderError {
+        /// Line of the document the template starts on
+        line: usize,
+        /// What went wrong
+        message: String,
+    },
+}
+
+impl fmt::Display for IfTError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IfTError::Parse(e) => write!(f, "{}", e),
+            IfTError::IfTFlagError(flag) => write!(f, "unable to parse flag {}", flag),
+            IfTError::IfTArgumentError(argument) => write!(f, "unable to use argument {}", argument),
+            IfTError::IfTRouteError(e) => write!(f, "unable to look up the default route: {}", e),
+            IfTError::IfTInterfaceError(message) => write!(f, "unable to list network interfaces: {}", message),
+            IfTError::IfTBindSpecError(spec) => write!(f, "unable to parse [{}], expected TEMPLATE:PORT", spec),
+            IfTError::IfTTimeoutError(timeout) => write!(f, "timed out after {:?}", timeout),
+            IfTError::IfTLintError(lint) => write!(f, "{}:{}: {}", lint.line, lint.column, lint.message),
+            IfTError::IfTCancelledError(message) => write!(f, "evaluation was cancelled: {}", message),
+            IfTError::IfTRenderError { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for IfTError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IfTError::Parse(e) => Some(e),
+            IfTError::IfTRouteError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for IfTError {
+    fn from(e: ParseError) -> IfTError {
+        IfTError::Parse(e)
+    }
 }
 
 /// # Evaluate a interface template
@@ -130,35 +211,403 @@ pub enum IfTError {
 /// #### FilterGlobal
 /// Filter on whether or not it is global according to [RFC6890](https://tools.ietf.org/rfc/rfc6890.txt)
 ///
+/// #### FilterScope <scope>
+/// Filter by the scope of the address, "interface-local", "link-local", "realm-local", "admin-local",
+/// "site-local", "organization-local" or "global". IPv6 scopes come from
+/// [RFC4291](https://tools.ietf.org/rfc/rfc4291.txt), IPv4 multicast scopes from
+/// [RFC5771](https://tools.ietf.org/rfc/rfc5771.txt) and [RFC2365](https://tools.ietf.org/rfc/rfc2365.txt).
+/// See [rfc::scope](rfc/fn.scope.html)
+/// ```
+/// use ift::eval;
+/// let ips = eval(r#"GetAllInterfaces | FilterIPv4 | FilterScope "link-local""#).unwrap();
+/// assert!(ips.contains(&"127.0.0.1".parse().unwrap()), "loopback is link-local");
+/// ```
+///
+/// #### FilterCIDR <network>
+/// Filter by the network the address is in, like "10.0.0.0/8" or "fd00::/8". A single address
+/// matches just itself. IPv6 link-local addresses are the same on every interface, so the network
+/// can have a zone, either the interface name or its index, like "fe80::/64%eth0"
+/// ```
+/// use ift::eval;
+/// assert_eq!(eval(r#"GetAllInterfaces | FilterCIDR "127.0.0.0/8""#).unwrap(), vec!["127.0.0.1".parse::<std::net::IpAddr>().unwrap()]);
+/// ```
+///
 /// #### FilterFirst/FilterLast
 /// Only return either the first IpAddr or the last IpAddr
 ///
 /// ### sorts
 /// #### SortBy <attribute>
-/// Sort by attribute "default", looks up the default interface and sorts it to the front
+/// Sort by attribute "default", looks up the default interface and sorts it to the front.
+/// The default route is only looked up when there is more than one IpAddr to sort. When it can
+/// not be looked up, like when the `ip` command is missing, the order is kept as is and a warning
+/// is printed once, see `DefaultRoutePolicy` to change that.
 ///
 /// ```
 /// use ift::evals;
-/// assert_eq!(true, evals("GetAllInterfaces").is_some());
-/// assert_eq!(false, evals("GetAllInterfaces | FilterIPv4 | FilterIPv6").is_some());
+/// assert_eq!(true, evals("GetAllInterfaces").unwrap().is_some());
+/// assert_eq!(false, evals("GetAllInterfaces | FilterIPv4 | FilterIPv6").unwrap().is_some());
+/// ```
+///
+/// ### with
+/// #### WithPort <port>
+/// Attach a port to every IpAddr, for `eval_socket_addrs`. `eval` ignores it
+///
 /// ```
-pub fn eval(s: &str) -> Result<Vec<IpAddr>, Error> {
-    let parsed = parse_ift_string(s)?;
-    Ok(parsed.result.into_iter().map(|ip2ni| ip2ni.ip_addr).collect())
+/// use ift::eval_socket_addrs;
+/// let addrs = eval_socket_addrs(r#"GetAllInterfaces | FilterIPv4 | WithPort "8080""#, 80).unwrap();
+/// assert!(addrs.iter().all(|addr| addr.port() == 8080));
+/// ```
+pub fn eval(s: &str) -> Result<Vec<IpAddr>, IfTError> {
+    let parsed = parse_ift_string(s, DefaultRoutePolicy::default())?;
+    Ok(ip_addrs(parsed))
 }
 
 /// Just like `eval`.
 /// Returns the first IpAddr as an option. None if empty vector.
-pub fn evals(s: &str) -> Option<IpAddr> {
-    eval(s).unwrap().into_iter().next()
+pub fn evals(s: &str) -> Result<Option<IpAddr>, IfTError> {
+    Ok(eval(s)?.into_iter().next())
+}
+
+/// Just like `eval`, but with a port, ready to bind to.
+///
+/// The port given by `WithPort` wins over `port`. IPv6 link-local addresses only mean something
+/// on their own interface, so they get its index as their scope id.
+///
+/// ```
+/// use ift::eval_socket_addrs;
+/// use std::net::TcpListener;
+/// for addr in eval_socket_addrs("GetAllInterfaces | FilterIPv4 | FilterFirst", 0).unwrap() {
+///     TcpListener::bind(addr).unwrap();
+/// }
+/// ```
+pub fn eval_socket_addrs(s: &str, port: u16) -> Result<Vec<SocketAddr>, IfTError> {
+    let parsed = parse_ift_string(s, DefaultRoutePolicy::default())?;
+    Ok(socket_addrs(parsed, port))
+}
+
+/// Just like `eval`, but keeps the details of the interface each IpAddr came from.
+///
+/// ```
+/// use ift::eval_detailed;
+/// for ip in eval_detailed("GetAllInterfaces").unwrap() {
+///     println!("{}/{} on {:?} {:?}", ip.ip_addr, ip.prefix, ip.interface_name, ip.flags);
+/// }
+/// ```
+pub fn eval_detailed(s: &str) -> Result<Vec<IpInterface>, IfTError> {
+    let parsed = parse_ift_string(s, DefaultRoutePolicy::default())?;
+    Ok(details(&parsed.result))
+}
+
+/// Just like `eval_detailed`, but returns what every stage of the template did. The output
+/// of the last stage is the result, and every stage lists the IpAddr's it dropped and why.
+///
+/// ```
+/// use ift::eval_traced;
+/// for stage in eval_traced("GetAllInterfaces | FilterIPv4 | FilterForwardable").unwrap() {
+///     println!("{}: {} in, {} out", stage.stage, stage.input.len(), stage.output.len());
+///     for dropped in stage.dropped {
+///         println!("  {} dropped: {}", dropped.ip.ip_addr, dropped.reason);
+///     }
+/// }
+/// ```
+pub fn eval_traced(s: &str) -> Result<Vec<TraceStage>, IfTError> {
+    trace_ift_string(s, DefaultRoutePolicy::default())
+}
+
+/// A template that is known to parse, so it can be kept around and evaluated many times.
+///
+/// ```
+/// use ift::Template;
+/// let template: Template = "GetAllInterfaces | FilterIPv4".parse().unwrap();
+/// assert_eq!(ift::eval("GetAllInterfaces | FilterIPv4").unwrap(), template.eval().unwrap());
+/// assert!("GetAllInterfaces | FilterNothing".parse::<Template>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Template {
+    source: String,
+    default_route: DefaultRoutePolicy,
+}
+
+impl Template {
+    /// Parse a template without evaluating it. Like `eval`, this only checks the grammar
+    pub fn parse(s: &str) -> Result<Template, IfTError> {
+        parse(s)?;
+        Ok(Template {
+            source: s.to_owned(),
+            default_route: DefaultRoutePolicy::default(),
+        })
+    }
+
+    /// Parse a template, and reject it with `IfTError::IfTLintError` when `lint` finds an error
+    /// in it, like an unknown flag or a port out of range. Use it for templates that come from
+    /// config, where a mistake should fail at startup instead of evaluating to nothing.
+    ///
+    /// ```
+    /// use ift::Template;
+    /// assert!(Template::parse(r#"GetAllInterfaces | FilterFlags "sideways""#).is_ok());
+    /// assert!(Template::parse_strict(r#"GetAllInterfaces | FilterFlags "sideways""#).is_err());
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Template, IfTError> {
+        Template::parse(s)?.deny_lint_errors()
+    }
+
+    pub(crate) fn deny_lint_errors(self) -> Result<Template, IfTError> {
+        match lint(&self.source).into_iter().find(|l| l.severity == Severity::Error) {
+            Some(error) => Err(IfTError::IfTLintError(error)),
+            None => Ok(self),
+        }
+    }
+
+    /// What to do when `SortBy "default"` or `GetPrivateInterfaces` can not look up the
+    /// default route
+    ///
+    /// ```
+    /// use ift::{DefaultRoutePolicy, Template};
+    /// let template = Template::parse("GetPrivateInterfaces").unwrap().with_default_route(DefaultRoutePolicy::Ignore);
+    /// assert!(template.eval().is_ok());
+    /// ```
+    pub fn with_default_route(mut self, policy: DefaultRoutePolicy) -> Template {
+        self.default_route = policy;
+        self
+    }
+
+    /// The template string
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// See `eval`
+    pub fn eval(&self) -> Result<Vec<IpAddr>, IfTError> {
+        let parsed = parse_ift_string(&self.source, self.default_route)?;
+        Ok(ip_addrs(parsed))
+    }
+
+    /// See `eval_socket_addrs`
+    pub fn eval_socket_addrs(&self, port: u16) -> Result<Vec<SocketAddr>, IfTError> {
+        let parsed = parse_ift_string(&self.source, self.default_route)?;
+        Ok(socket_addrs(parsed, port))
+    }
+
+    /// See `eval_detailed`
+    pub fn eval_detailed(&self) -> Result<Vec<IpInterface>, IfTError> {
+        let parsed = parse_ift_string(&self.source, self.default_route)?;
+        Ok(details(&parsed.result))
+    }
+
+    /// See `lint`. A `Template` always parses, but can still have problems
+    pub fn lint(&self) -> Vec<Lint> {
+        lint(&self.source)
+    }
+
+    /// See `eval_traced`
+    pub fn eval_traced(&self) -> Result<Vec<TraceStage>, IfTError> {
+        trace_ift_string(&self.source, self.default_route)
+    }
+
+    /// See `Watcher`
+    pub fn watch(&self, poll: Duration) -> Watcher {
+        Watcher::new(self.clone(), poll)
+    }
+
+    /// See `Watcher`, yields the interface of every IpAddr like `eval_detailed`
+    pub fn watch_detailed(&self, poll: Duration) -> Watcher<IpInterface> {
+        Watcher::new_detailed(self.clone(), poll)
+    }
+}
+
+impl FromStr for Template {
+    type Err = IfTError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Template::parse(s)
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// With the `serde` feature a template is a string, that has to pass `Template::parse_strict` to
+/// be deserialized.
+///
+/// Only the string is serialized, not the `DefaultRoutePolicy`. Deserialized templates get the
+/// default policy, so keep the policy next to the template when it matters.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let template: ift::Template = serde_json::from_str(r#""GetPrivateInterfaces | FilterIPv4""#).unwrap();
+/// assert_eq!("GetPrivateInterfaces | FilterIPv4", template.as_str());
+/// let err = serde_json::from_str::<ift::Template>(r#""GetPrivateInterfaces | FilterIpv4""#).unwrap_err();
+/// assert!(err.to_string().contains("did you mean `FilterIPv4`?"));
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Template {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Template {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Template::parse_strict(&source)
+            .map_err(|e| serde::de::Error::custom(format!("invalid template [{}]: {}", source, e)))
+    }
+}
+
+/// An IpAddr produced by a template, along with the interface that it came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IpInterface {
+    /// The address
+    pub ip_addr: IpAddr,
+    /// Prefix length of the network the address is on
+    pub prefix: u8,
+    /// Name of the interface the address is on
+    pub interface_name: Option<String>,
+    /// Index of the interface the address is on
+    pub interface_index: Option<u32>,
+    /// Flags set on the interface, any of "up", "broadcast", "loopback", "point-to-point" or "multicast"
+    pub flags: Vec<String>,
+    /// Scope id of an IPv6 link-local address, the index of the interface it is on. Without it
+    /// the address can not be bound to or connected to
+    pub scope_id: Option<u32>,
+}
+
+impl IpInterface {
+    /// The address, with the interface name as its zone when it has a scope id, like `fe80::1%eth0`
+    ///
+    /// ```
+    /// for ip in ift::eval_detailed("GetAllInterfaces | FilterIPv6").unwrap() {
+    ///     println!("{}", ip.to_zoned_string());
+    /// }
+    /// ```
+    pub fn to_zoned_string(&self) -> String {
+        match (self.scope_id, &self.interface_name) {
+            (Some(_), Some(name)) => format!("{}%{}", self.ip_addr, name),
+            (Some(scope_id), None) => format!("{}%{}", self.ip_addr, scope_id),
+            (None, _) => self.ip_addr.to_string(),
+        }
+    }
+}
+
+impl<'a> From<&'a Ip2NetworkInterface> for IpInterface {
+    fn from(ip: &'a Ip2NetworkInterface) -> IpInterface {
+        IpInterface {
+            ip_addr: ip.ip_addr,
+            prefix: ip.prefix,
+            interface_name: ip.interface.as_ref().map(|int| int.name.clone()),
+            interface_index: ip.interface.as_ref().map(|int| int.index),
+            flags: ip.interface.as_ref().map_or(vec![], |int| interface_flags(int)),
+            scope_id: ip.scope_id(),
+        }
+    }
+}
+
+/// A network interface and all of its addresses, as seen by the producers
+///
+/// ```
+/// for int in ift::interfaces().unwrap() {
+///     println!("{} {:?} {:?}", int.name, int.flags, int.addresses);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interface {
+    /// Name of the interface, like "eth0" or "en0"
+    pub name: String,
+    /// Index of the interface
+    pub index: u32,
+    /// Hardware address, if it has one
+    pub mac: Option<String>,
+    /// Maximum transmission unit, where the os tells us
+    pub mtu: Option<u32>,
+    /// Flags set on the interface, any of "up", "broadcast", "loopback", "point-to-point" or "multicast"
+    pub flags: Vec<String>,
+    /// Addresses on the interface, with the prefix of the network they are on
+    pub addresses: Vec<IpNet>,
+}
+
+/// Every interface that the producers would see, including the ones without addresses
+pub fn interfaces() -> Result<Vec<Interface>, IfTError> {
+    Ok(network_interfaces()?
+        .into_iter()
+        .map(|int| Interface {
+            mac: int.mac.map(|mac| mac.to_string()),
+            mtu: read_mtu(&int.name),
+            flags: interface_flags(&int),
+            addresses: int
+                .ips
+                .iter()
+                .filter_map(|ipn| IpNet::new(ipn.ip(), ipn.prefix()).ok())
+                .collect(),
+            index: int.index,
+            name: int.name,
+        })
+        .collect())
+}
+
+/// Name of the interface that the default route goes through, `None` if there is no default route
+pub fn default_interface_name() -> Result<Option<String>, IfTError> {
+    let name = read_default_interface_name().map_err(IfTError::IfTRouteError)?;
+    Ok(if name.is_empty() { None } else { Some(name) })
+}
+
+fn interface_flags(int: &NetworkInterface) -> Vec<String> {
+    let mut flags = vec![];
+    if int.is_up() {
+        flags.push("up".to_owned());
+    }
+    if int.is_broadcast() {
+        flags.push("broadcast".to_owned());
+    }
+    if int.is_loopback() {
+        flags.push("loopback".to_owned());
+    }
+    if int.is_point_to_point() {
+        flags.push("point-to-point".to_owned());
+    }
+    if int.is_multicast() {
+        flags.push("multicast".to_owned());
+    }
+    flags
+}
+
+#[cfg(target_os = "linux")]
+fn read_mtu(interface_name: &str) -> Option<u32> {
+    ::std::fs::read_to_string(format!("/sys/class/net/{}/mtu", interface_name))
+        .ok()
+        .and_then(|mtu| mtu.trim().parse().ok())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_mtu(_interface_name: &str) -> Option<u32> {
+    None
 }
 
 #[derive(Debug)]
 struct Ip2NetworkInterface {
     ip_addr: IpAddr,
+    prefix: u8,
     // 1 network interface can have multiple ips, but this way we can filter on both of them
     // all it takes is doing the cross product at the beginning
-    interface: Option<Rc<NetworkInterface>>,
+    interface: Option<Arc<NetworkInterface>>,
+    // set by WithPort
+    port: Option<u16>,
+}
+
+impl Ip2NetworkInterface {
+    fn scope_id(&self) -> Option<u32> {
+        match (self.ip_addr, &self.interface) {
+            (IpAddr::V6(ip), Some(int)) if is_unicast_link_local(&ip) => Some(int.index),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -166,10 +615,40 @@ struct IfTResult {
     result: Vec<Ip2NetworkInterface>,
 }
 
-fn parse_ift_string(template_str: &str) -> Result<IfTResult, Error> {
-    let template = IfTParser::parse(Rule::template, template_str)?.next().unwrap();
+fn parse_ift_string(template_str: &str, default_route: DefaultRoutePolicy) -> Result<IfTResult, IfTError> {
+    let template = parse(template_str)?;
     let rfc: WithRfc6890 = WithRfc6890::create();
-    Ok(parse_expression(template, &rfc)?)
+    parse_expression(template, &rfc, default_route, None)
+}
+
+fn trace_ift_string(template_str: &str, default_route: DefaultRoutePolicy) -> Result<Vec<TraceStage>, IfTError> {
+    let template = parse(template_str)?;
+    let mut trace = vec![];
+    parse_expression(template, &WithRfc6890::create(), default_route, Some(&mut trace))?;
+    Ok(trace)
+}
+
+fn ip_addrs(parsed: IfTResult) -> Vec<IpAddr> {
+    parsed.result.into_iter().map(|ip2ni| ip2ni.ip_addr).collect()
+}
+
+fn socket_addrs(parsed: IfTResult, default_port: u16) -> Vec<SocketAddr> {
+    parsed
+        .result
+        .into_iter()
+        .map(|ip2ni| {
+            let port = ip2ni.port.unwrap_or(default_port);
+            match ip2ni.ip_addr {
+                IpAddr::V4(ip) => SocketAddr::V4(SocketAddrV4::new(ip, port)),
+                IpAddr::V6(ip) => SocketAddr::V6(SocketAddrV6::new(ip, port, 0, ip2ni.scope_id().unwrap_or(0))),
+            }
+        })
+        .collect()
+}
+
+/// fe80::/10, the addresses that need a scope id to be used
+fn is_unicast_link_local(ip: &Ipv6Addr) -> bool {
+    ip.segments()[0] & 0xffc0 == 0xfe80
 }
 
 enum IfTFlag {
@@ -188,6 +667,51 @@ impl FromStr for IfTFlag {
     }
 }
 
+/// The argument of FilterCIDR, a network with an optional zone like "fe80::/64%eth0"
+struct IfTNetwork {
+    net: IpNet,
+    zone: Option<String>,
+}
+impl FromStr for IfTNetwork {
+    type Err = IfTError;
+
+    fn from_str(network: &str) -> ::std::result::Result<Self, Self::Err> {
+        let (net, zone) = match network.find('%') {
+            Some(i) => (&network[..i], Some(&network[i + 1..])),
+            None => (network, None),
+        };
+        let net = net
+            .parse::<IpNet>()
+            .or_else(|_| net.parse::<IpAddr>().map(IpNet::from))
+            .map_err(|_| IfTError::IfTArgumentError(network.to_owned()))?;
+        match zone {
+            Some("") => Err(IfTError::IfTArgumentError(network.to_owned())),
+            zone => Ok(IfTNetwork {
+                net,
+                zone: zone.map(str::to_owned),
+            }),
+        }
+    }
+}
+
+impl IfTNetwork {
+    fn contains(&self, ip: &Ip2NetworkInterface) -> bool {
+        self.net.contains(&ip.ip_addr)
+            && match ip.interface {
+                Some(ref int) => self.in_zone(&int.name, int.index),
+                None => self.zone.is_none(),
+            }
+    }
+
+    /// Zones are interface names, or their index
+    fn in_zone(&self, interface_name: &str, interface_index: u32) -> bool {
+        match self.zone {
+            Some(ref zone) => *zone == interface_name || *zone == interface_index.to_string(),
+            None => true,
+        }
+    }
+}
+
 fn filter_by_flag(ip: &Ip2NetworkInterface, flag: &IfTFlag) -> bool {
     match ip.interface.clone() {
         Some(int) => match flag {
@@ -205,19 +729,33 @@ fn filter_by_name(ip: &Ip2NetworkInterface, interface_name: &str) -> bool {
     }
 }
 
-fn all_interfaces() -> Vec<Ip2NetworkInterface> {
+/// pnet gives back nothing when the os will not list the interfaces, but there is always a loopback
+fn network_interfaces() -> Result<Vec<NetworkInterface>, IfTError> {
     let interfaces = datalink::interfaces();
+    if interfaces.is_empty() {
+        Err(IfTError::IfTInterfaceError(
+            "no interfaces found, not even loopback".to_owned(),
+        ))
+    } else {
+        Ok(interfaces)
+    }
+}
+
+fn all_interfaces() -> Result<Vec<Ip2NetworkInterface>, IfTError> {
+    let interfaces = network_interfaces()?;
     let mut ret: Vec<Ip2NetworkInterface> = vec![];
     for interface in interfaces {
-        let rc = Rc::new(interface);
-        for ipn in (*rc.ips).iter() {
+        let interface = Arc::new(interface);
+        for ipn in interface.ips.iter() {
             ret.push(Ip2NetworkInterface {
                 ip_addr: ipn.ip(),
-                interface: Some(rc.clone()),
+                prefix: ipn.prefix(),
+                interface: Some(interface.clone()),
+                port: None,
             })
         }
     }
-    ret
+    Ok(ret)
 }
 
 fn rule_filter_name(iter: Vec<Ip2NetworkInterface>, name: &str) -> IfTResult {
@@ -226,52 +764,80 @@ fn rule_filter_name(iter: Vec<Ip2NetworkInterface>, name: &str) -> IfTResult {
     }
 }
 
-fn parse_expression(pair: Pair<'_, Rule>, rfc: &WithRfc6890) -> Result<IfTResult, Error> {
+fn parse_expression(
+    pair: Pair<'_, Rule>,
+    rfc: &WithRfc6890,
+    default_route: DefaultRoutePolicy,
+    mut trace: Option<&mut Vec<TraceStage>>,
+) -> Result<IfTResult, IfTError> {
     match pair.as_rule() {
         Rule::expression => {
-            let mut iter = pair.into_inner();
-            let producer_pair = iter.next().unwrap().into_inner().next().unwrap();
-            let mut base: IfTResult = parse_producer(producer_pair)?;
+            let mut iter = pair.clone().into_inner();
+            let producer_pair = match iter.next() {
+                Some(producer) => inner(&producer)?,
+                None => Err(unexpected(&pair))?,
+            };
+            // one snapshot, so the trace shows exactly what the producer chose from
+            let interfaces = all_interfaces()?;
+            let input = trace.as_ref().map(|_| details(&interfaces));
+            let mut base: IfTResult = parse_producer(producer_pair.clone(), interfaces, default_route)?;
+            if let (Some(trace), Some(input)) = (trace.as_mut(), input) {
+                trace.push(TraceStage::new(&producer_pair, input, details(&base.result), rfc));
+            }
 
             for p in iter {
-                match p.as_rule() {
-                    Rule::filter => base = parse_filter(base, p.into_inner().next().unwrap(), rfc)?,
-                    Rule::sort => base = parse_sort(base, p.into_inner().next().unwrap())?,
-                    _ => unreachable!("only filters and sorts should follow. saw {:?}", p.as_rule()),
+                let input = trace.as_ref().map(|_| details(&base.result));
+                let rule = p.as_rule();
+                let stage = inner(&p)?;
+                match rule {
+                    Rule::filter => base = parse_filter(base, stage.clone(), rfc)?,
+                    Rule::sort => base = parse_sort(base, stage.clone(), default_route)?,
+                    Rule::with => base = parse_with(base, stage.clone())?,
+                    _ => Err(unexpected(&p))?,
+                }
+                if let (Some(trace), Some(input)) = (trace.as_mut(), input) {
+                    trace.push(TraceStage::new(&stage, input, details(&base.result), rfc));
                 }
             }
             Ok(base)
         }
-        _ => unreachable!("unable to parse rule {:?}", pair.as_rule()),
+        _ => Err(unexpected(&pair))?,
     }
 }
 
-fn parse_producer(pair: Pair<'_, Rule>) -> Result<IfTResult, Error> {
+fn details(result: &[Ip2NetworkInterface]) -> Vec<IpInterface> {
+    result.iter().map(IpInterface::from).collect()
+}
+
+fn parse_producer(
+    pair: Pair<'_, Rule>,
+    interfaces: Vec<Ip2NetworkInterface>,
+    default_route: DefaultRoutePolicy,
+) -> Result<IfTResult, IfTError> {
     let rfc = WithRfc6890::create();
 
     match pair.as_rule() {
         Rule::GetInterface => {
-            let interface_name = pair.into_inner().next().unwrap().as_str();
-            Ok(rule_filter_name(all_interfaces(), interface_name))
+            let interface_name = inner(&pair)?.as_str();
+            Ok(rule_filter_name(interfaces, interface_name))
         }
-        Rule::GetAllInterfaces => Ok(IfTResult {
-            result: all_interfaces(),
-        }),
+        Rule::GetAllInterfaces => Ok(IfTResult { result: interfaces }),
         Rule::GetPrivateInterfaces => rule_sort_by_attribute(
             IfTResult {
-                result: all_interfaces()
+                result: interfaces
                     .into_iter()
                     .filter(|ip| filter_by_flag(&ip, &IfTFlag::UP))
                     .filter(|ip| rfc.is_forwardable(&ip.ip_addr))
                     .collect(),
             },
             "default",
+            default_route,
         ),
-        _ => unreachable!("unable to parse rule {:?}", pair.as_rule()),
+        _ => Err(unexpected(&pair))?,
     }
 }
 
-fn parse_filter(prev: IfTResult, pair: Pair<'_, Rule>, rfc: &WithRfc6890) -> Result<IfTResult, Error> {
+fn parse_filter(prev: IfTResult, pair: Pair<'_, Rule>, rfc: &WithRfc6890) -> Result<IfTResult, IfTError> {
     Ok(match pair.as_rule() {
         Rule::FilterIPv4 => IfTResult {
             result: prev
@@ -288,11 +854,11 @@ fn parse_filter(prev: IfTResult, pair: Pair<'_, Rule>, rfc: &WithRfc6890) -> Res
                 .collect(),
         },
         Rule::FilterName => {
-            let name = pair.into_inner().next().unwrap().as_str();
+            let name = inner(&pair)?.as_str();
             rule_filter_name(prev.result, name)
         }
         Rule::FilterFlags => {
-            let flag = pair.into_inner().next().unwrap().as_str();
+            let flag = inner(&pair)?.as_str();
             let flag: IfTFlag = flag.parse()?;
             IfTResult {
                 result: prev.result.into_iter().filter(|ip| filter_by_flag(ip, &flag)).collect(),
@@ -312,13 +878,31 @@ fn parse_filter(prev: IfTResult, pair: Pair<'_, Rule>, rfc: &WithRfc6890) -> Res
                 .filter(|ip| rfc.is_global(&ip.ip_addr))
                 .collect(),
         },
+        Rule::FilterScope => {
+            let scope = inner(&pair)?.as_str();
+            let scope: Scope = scope.parse()?;
+            IfTResult {
+                result: prev
+                    .result
+                    .into_iter()
+                    .filter(|ip| rfc::scope(&ip.ip_addr) == scope)
+                    .collect(),
+            }
+        }
+        Rule::FilterCIDR => {
+            let network = inner(&pair)?.as_str();
+            let network: IfTNetwork = network.parse()?;
+            IfTResult {
+                result: prev.result.into_iter().filter(|ip| network.contains(ip)).collect(),
+            }
+        }
         Rule::FilterFirst => IfTResult {
             result: prev.result.into_iter().next().into_iter().collect(),
         },
         Rule::FilterLast => IfTResult {
             result: prev.result.into_iter().last().into_iter().collect(),
         },
-        _ => unreachable!("unable to parse rule {:?}", pair.as_rule()),
+        _ => Err(unexpected(&pair))?,
     })
 }
 
@@ -339,23 +923,48 @@ fn sort_default_less(
     }
 }
 
-fn parse_sort(prev: IfTResult, pair: Pair<'_, Rule>) -> Result<IfTResult, Error> {
+fn parse_sort(prev: IfTResult, pair: Pair<'_, Rule>, default_route: DefaultRoutePolicy) -> Result<IfTResult, IfTError> {
     match pair.as_rule() {
         Rule::SortBy => {
-            let attribute: &str = pair.into_inner().next().unwrap().as_str();
-            rule_sort_by_attribute(prev, attribute)
+            let attribute: &str = inner(&pair)?.as_str();
+            rule_sort_by_attribute(prev, attribute, default_route)
         }
-        _ => unreachable!("unable to parse rule {:?}", pair.as_rule()),
+        _ => Err(unexpected(&pair))?,
     }
 }
 
-fn rule_sort_by_attribute(prev: IfTResult, attribute: &str) -> Result<IfTResult, Error> {
-    let default_interface = read_default_interface_name()?;
-    let sorter = match attribute {
-        "default" => Ok(sort_default_less(default_interface)),
-        _ => Err(IfTError::IfTArgumentError(attribute.to_owned())),
-    }?;
+fn parse_with(prev: IfTResult, pair: Pair<'_, Rule>) -> Result<IfTResult, IfTError> {
+    match pair.as_rule() {
+        Rule::WithPort => {
+            let port = inner(&pair)?.as_str();
+            let port: u16 = port.parse().map_err(|_| IfTError::IfTArgumentError(port.to_owned()))?;
+            Ok(IfTResult {
+                result: prev
+                    .result
+                    .into_iter()
+                    .map(|ip| Ip2NetworkInterface { port: Some(port), ..ip })
+                    .collect(),
+            })
+        }
+        _ => Err(unexpected(&pair))?,
+    }
+}
+
+fn rule_sort_by_attribute(
+    prev: IfTResult,
+    attribute: &str,
+    default_route: DefaultRoutePolicy,
+) -> Result<IfTResult, IfTError> {
+    if attribute != "default" {
+        Err(IfTError::IfTArgumentError(attribute.to_owned()))?
+    }
     let mut result = prev.result;
-    result.sort_by(sorter);
+    // nothing to reorder, so do not bother looking up the default route
+    if result.len() < 2 {
+        return Ok(IfTResult { result });
+    }
+    if let Some(default_interface) = default_route.lookup()? {
+        result.sort_by(sort_default_less(default_interface));
+    }
     Ok(IfTResult { result })
 }
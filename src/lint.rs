@@ -0,0 +1,441 @@
+//! Checking templates for mistakes without evaluating them, see `lint`
+use crate::{
+    diagnostics::{line_column, parse, ParseError},
+    grammar::Rule,
+    rfc::Scope,
+    IfTFlag, IfTNetwork,
+};
+use std::{fmt, ops::Range};
+
+/// How bad a `Lint` is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Severity {
+    /// A shorter way to write the same template
+    Note,
+    /// The template works, but probably not the way it was meant to
+    Warning,
+    /// The template fails to evaluate, or can never produce anything
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// A problem found in a template by `lint`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lint {
+    /// How bad it is
+    pub severity: Severity,
+    /// What is wrong
+    pub message: String,
+    /// Byte range of the template that the lint is about
+    pub span: Range<usize>,
+    /// Line the span starts on, starting at 1
+    pub line: usize,
+    /// Column the span starts on, in characters starting at 1
+    pub column: usize,
+}
+
+impl Lint {
+    fn new(template: &str, severity: Severity, message: String, span: Range<usize>) -> Lint {
+        let (line, column) = line_column(template, span.start);
+        Lint {
+            severity,
+            message,
+            span,
+            line,
+            column,
+        }
+    }
+
+    /// The lint with the line of the template it is about, and carets under the span
+    ///
+    /// ```
+    /// let template = r#"GetAllInterfaces | FilterFlags "upp""#;
+    /// let lints = ift::lint(template);
+    /// assert_eq!(
+    ///     r#"error: unknown flag "upp", expected "up" or "down"
+    ///  --> 1:33
+    ///   |
+    /// 1 | GetAllInterfaces | FilterFlags "upp"
+    ///   |                                 ^^^
+    /// "#,
+    ///     lints[0].annotate(template)
+    /// );
+    /// ```
+    pub fn annotate(&self, template: &str) -> String {
+        let line_start = template[..self.span.start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = template[line_start..]
+            .find('\n')
+            .map_or(template.len(), |i| line_start + i);
+        let carets = template[self.span.start..self.span.end.min(line_end).max(self.span.start)]
+            .chars()
+            .count()
+            .max(1);
+        let number = self.line.to_string();
+        let pad = " ".repeat(number.len());
+        format!(
+            "{}: {}\n{}--> {}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.severity,
+            self.message,
+            pad,
+            self.line,
+            self.column,
+            pad,
+            number,
+            &template[line_start..line_end],
+            pad,
+            " ".repeat(self.column - 1),
+            "^".repeat(carets)
+        )
+    }
+}
+
+/// One producer, filter or sort of a template
+struct Stage<'i> {
+    rule: Rule,
+    argument: Option<&'i str>,
+    span: Range<usize>,
+    argument_span: Range<usize>,
+}
+
+impl<'i> Stage<'i> {
+    /// `GetInterface "x"` filters just like `FilterName "x"`
+    fn filter_rule(&self) -> Rule {
+        match self.rule {
+            Rule::GetInterface => Rule::FilterName,
+            rule => rule,
+        }
+    }
+}
+
+/// # Check a template for mistakes
+///
+/// Reports parse errors, unknown flags, scopes, networks, sort attributes and ports, stages that contradict each
+/// other so the template can never produce anything, stages that do nothing and templates that
+/// can be written shorter. Nothing is evaluated, so it does not look at the network interfaces.
+///
+/// ```
+/// use ift::{lint, Severity};
+/// assert!(lint("GetPrivateInterfaces | FilterIPv4").is_empty());
+/// let lints = lint("GetAllInterfaces | FilterIPv4 | FilterIPv6");
+/// assert_eq!(Severity::Error, lints[0].severity);
+/// assert_eq!("FilterIPv6 after FilterIPv4 never matches anything", lints[0].message);
+/// ```
+pub fn lint(template: &str) -> Vec<Lint> {
+    let stages = match stages(template) {
+        Ok(stages) => stages,
+        Err(e) => return vec![Lint::new(template, Severity::Error, e.message, e.span)],
+    };
+    let mut lints = vec![];
+    let mut add =
+        |severity, message, span: &Range<usize>| lints.push(Lint::new(template, severity, message, span.clone()));
+
+    for (i, stage) in stages.iter().enumerate() {
+        let argument = stage.argument.unwrap_or("");
+        match stage.rule {
+            Rule::FilterFlags if argument.parse::<IfTFlag>().is_err() => add(
+                Severity::Error,
+                format!(r#"unknown flag "{}", expected "up" or "down""#, argument),
+                &stage.argument_span,
+            ),
+            Rule::FilterScope if argument.parse::<Scope>().is_err() => add(
+                Severity::Error,
+                format!(
+                    r#"unknown scope "{}", expected one of "interface-local", "link-local", "realm-local", "admin-local", "site-local", "organization-local" or "global""#,
+                    argument
+                ),
+                &stage.argument_span,
+            ),
+            Rule::SortBy if argument != "default" => add(
+                Severity::Error,
+                format!(r#"unknown sort attribute "{}", expected "default""#, argument),
+                &stage.argument_span,
+            ),
+            Rule::FilterCIDR if argument.parse::<IfTNetwork>().is_err() => add(
+                Severity::Error,
+                format!(
+                    r#"network "{}" is not like "10.0.0.0/8", "fe80::1" or "fe80::/64%eth0""#,
+                    argument
+                ),
+                &stage.argument_span,
+            ),
+            Rule::WithPort if argument.parse::<u16>().is_err() => add(
+                Severity::Error,
+                format!(r#"port "{}" is not a number from 0 to 65535"#, argument),
+                &stage.argument_span,
+            ),
+            Rule::GetInterface | Rule::FilterName if argument.is_empty() => add(
+                Severity::Error,
+                "an empty interface name never matches anything".to_owned(),
+                &stage.argument_span,
+            ),
+            _ => (),
+        }
+
+        for earlier in &stages[..i] {
+            let same_filter = earlier.filter_rule() == stage.filter_rule();
+            match (earlier.filter_rule(), stage.rule) {
+                (Rule::FilterIPv4, Rule::FilterIPv6) | (Rule::FilterIPv6, Rule::FilterIPv4) => add(
+                    Severity::Error,
+                    format!("{:?} after {:?} never matches anything", stage.rule, earlier.rule),
+                    &stage.span,
+                ),
+                (Rule::FilterName, Rule::FilterName)
+                | (Rule::FilterFlags, Rule::FilterFlags)
+                | (Rule::FilterScope, Rule::FilterScope)
+                    if earlier.argument != stage.argument =>
+                {
+                    add(
+                        Severity::Error,
+                        format!(
+                            "{} after {} never matches anything",
+                            source(template, stage),
+                            source(template, earlier)
+                        ),
+                        &stage.span,
+                    )
+                }
+                (Rule::GetPrivateInterfaces, Rule::FilterFlags) if stage.argument == Some("down") => add(
+                    Severity::Error,
+                    format!(
+                        "{} never matches anything, GetPrivateInterfaces only produces interfaces that are up",
+                        source(template, stage)
+                    ),
+                    &stage.span,
+                ),
+                (Rule::GetPrivateInterfaces, Rule::FilterFlags) if stage.argument == Some("up") => add(
+                    Severity::Warning,
+                    format!(
+                        "{} is redundant, GetPrivateInterfaces already is up",
+                        source(template, stage)
+                    ),
+                    &stage.span,
+                ),
+                (Rule::GetPrivateInterfaces, Rule::FilterForwardable) => add(
+                    Severity::Warning,
+                    "FilterForwardable is redundant, GetPrivateInterfaces already is forwardable".to_owned(),
+                    &stage.span,
+                ),
+                (Rule::GetPrivateInterfaces, Rule::SortBy) if stage.argument == Some("default") => add(
+                    Severity::Warning,
+                    format!(
+                        "{} is redundant, GetPrivateInterfaces already is sorted by default",
+                        source(template, stage)
+                    ),
+                    &stage.span,
+                ),
+                (Rule::WithPort, Rule::WithPort) if earlier.argument != stage.argument => add(
+                    Severity::Warning,
+                    format!("{} overrides {}", source(template, stage), source(template, earlier)),
+                    &stage.span,
+                ),
+                (Rule::FilterFirst, Rule::SortBy) | (Rule::FilterLast, Rule::SortBy) => add(
+                    Severity::Warning,
+                    format!(
+                        "{} after {:?} has nothing to sort, sort before picking one",
+                        source(template, stage),
+                        earlier.rule
+                    ),
+                    &stage.span,
+                ),
+                _ if same_filter && earlier.argument == stage.argument => add(
+                    Severity::Warning,
+                    format!(
+                        "{} is redundant, {} already ran",
+                        source(template, stage),
+                        source(template, earlier)
+                    ),
+                    &stage.span,
+                ),
+                _ => (),
+            }
+        }
+    }
+
+    let rules: Vec<(Rule, Option<&str>)> = stages.iter().map(|s| (s.rule, s.argument)).collect();
+    if rules.len() >= 2 && rules[0].0 == Rule::GetAllInterfaces && rules[1].0 == Rule::FilterName {
+        let span = stages[0].span.start..stages[1].span.end;
+        add(
+            Severity::Note,
+            format!(
+                r#"can be written as GetInterface "{}""#,
+                stages[1].argument.unwrap_or("")
+            ),
+            &span,
+        );
+    }
+    if rules.len() >= 4
+        && rules[..4]
+            == [
+                (Rule::GetAllInterfaces, None),
+                (Rule::FilterFlags, Some("up")),
+                (Rule::FilterForwardable, None),
+                (Rule::SortBy, Some("default")),
+            ]
+    {
+        let span = stages[0].span.start..stages[3].span.end;
+        add(
+            Severity::Note,
+            "can be written as GetPrivateInterfaces".to_owned(),
+            &span,
+        );
+    }
+    lints
+}
+
+fn stages(template: &str) -> Result<Vec<Stage<'_>>, ParseError> {
+    let mut stages = vec![];
+    for wrapper in parse(template)?.into_inner() {
+        for pair in wrapper.into_inner() {
+            let argument = pair.clone().into_inner().next();
+            let span = pair.as_span().start()..pair.as_span().end();
+            stages.push(Stage {
+                rule: pair.as_rule(),
+                argument: argument.clone().map(|a| a.as_str()),
+                argument_span: argument.map_or(span.clone(), |a| a.as_span().start()..a.as_span().end()),
+                span,
+            });
+        }
+    }
+    Ok(stages)
+}
+
+fn source<'i>(template: &'i str, stage: &Stage<'_>) -> &'i str {
+    &template[stage.span.clone()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{lint, Severity};
+
+    fn messages(template: &str) -> Vec<(Severity, String)> {
+        lint(template).into_iter().map(|l| (l.severity, l.message)).collect()
+    }
+
+    #[test]
+    fn test_lint_clean() {
+        assert!(lint("GetPrivateInterfaces").is_empty());
+        assert!(lint(r#"GetInterface "eth0" | FilterIPv6 | FilterScope "global" | FilterFirst"#).is_empty());
+        assert!(lint(r#"GetAllInterfaces | SortBy "default" | FilterLast"#).is_empty());
+    }
+
+    #[test]
+    fn test_lint_parse_error() {
+        let lints = lint("GetAllInterfaces | FilterIpv4");
+        assert_eq!(1, lints.len());
+        assert_eq!(Severity::Error, lints[0].severity);
+        assert_eq!(
+            "unknown filter `FilterIpv4`; did you mean `FilterIPv4`?",
+            lints[0].message
+        );
+        assert_eq!((1, 20), (lints[0].line, lints[0].column));
+    }
+
+    #[test]
+    fn test_lint_arguments() {
+        assert_eq!(
+            vec![
+                (
+                    Severity::Error,
+                    r#"unknown flag "upp", expected "up" or "down""#.to_owned()
+                ),
+                (
+                    Severity::Error,
+                    r#"unknown sort attribute "name", expected "default""#.to_owned()
+                ),
+            ],
+            messages(r#"GetAllInterfaces | FilterFlags "upp" | SortBy "name""#)
+        );
+        assert_eq!(
+            Severity::Error,
+            lint(r#"GetAllInterfaces | FilterScope "planet""#)[0].severity
+        );
+        assert_eq!(
+            vec![(
+                Severity::Error,
+                r#"port "80800" is not a number from 0 to 65535"#.to_owned()
+            )],
+            messages(r#"GetAllInterfaces | WithPort "80800""#)
+        );
+        assert_eq!(
+            vec![(
+                Severity::Error,
+                r#"network "fe80::/64%" is not like "10.0.0.0/8", "fe80::1" or "fe80::/64%eth0""#.to_owned()
+            )],
+            messages(r#"GetAllInterfaces | FilterCIDR "fe80::/64%""#)
+        );
+        assert_eq!(
+            vec![(
+                Severity::Warning,
+                r#"WithPort "443" overrides WithPort "80""#.to_owned()
+            )],
+            messages(r#"GetAllInterfaces | WithPort "80" | WithPort "443""#)
+        );
+    }
+
+    #[test]
+    fn test_lint_contradictions() {
+        assert_eq!(
+            vec![(
+                Severity::Error,
+                r#"FilterName "b" after GetInterface "a" never matches anything"#.to_owned()
+            )],
+            messages(r#"GetInterface "a" | FilterName "b""#)
+        );
+        assert_eq!(
+            vec![(
+                Severity::Error,
+                r#"FilterFlags "down" never matches anything, GetPrivateInterfaces only produces interfaces that are up"#
+                    .to_owned()
+            )],
+            messages(r#"GetPrivateInterfaces | FilterFlags "down""#)
+        );
+    }
+
+    #[test]
+    fn test_lint_order_and_redundancy() {
+        assert_eq!(
+            vec![
+                (
+                    Severity::Warning,
+                    r#"SortBy "default" after FilterFirst has nothing to sort, sort before picking one"#.to_owned()
+                ),
+                (
+                    Severity::Warning,
+                    "FilterIPv4 is redundant, FilterIPv4 already ran".to_owned()
+                ),
+            ],
+            messages(r#"GetAllInterfaces | FilterIPv4 | FilterFirst | SortBy "default" | FilterIPv4"#)
+        );
+        assert_eq!(
+            vec![(
+                Severity::Warning,
+                "FilterForwardable is redundant, GetPrivateInterfaces already is forwardable".to_owned()
+            )],
+            messages("GetPrivateInterfaces | FilterForwardable")
+        );
+    }
+
+    #[test]
+    fn test_lint_shorter_forms() {
+        assert_eq!(
+            vec![(Severity::Note, "can be written as GetPrivateInterfaces".to_owned())],
+            messages(r#"GetAllInterfaces | FilterFlags "up" | FilterForwardable | SortBy "default" | FilterIPv4"#)
+        );
+        assert_eq!(
+            vec![(Severity::Note, r#"can be written as GetInterface "en0""#.to_owned())],
+            messages(r#"GetAllInterfaces | FilterName "en0""#)
+        );
+    }
+}
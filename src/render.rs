@@ -0,0 +1,92 @@
+//! Rendering templates inside of other documents, see `render`
+use crate::{eval_detailed, IfTError, IpInterface};
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+const ESCAPED_OPEN: &str = "{{{{";
+
+/// # Render the templates inside of a document
+///
+/// Every template between `{{` and `}}` is evaluated and replaced by the IpAddr's it produced,
+/// separated by a space. Link-local IPv6 addresses keep their zone, like `fe80::1%eth0`. A template that produces nothing is replaced by nothing, so use
+/// `FilterFirst` or `ift eval --require` where exactly one address is needed. Everything
+/// outside of the delimiters is copied as is, except for `{{{{` which is written as a literal `{{`.
+///
+/// ```
+/// use ift::render;
+/// assert_eq!(
+///     "listen 127.0.0.1:8080;",
+///     render(r#"listen {{ GetAllInterfaces | FilterIPv4 | FilterScope "link-local" | FilterFirst }}:8080;"#).unwrap()
+/// );
+/// assert_eq!("{{ not a template }}", render("{{{{ not a template }}").unwrap());
+/// ```
+pub fn render(document: &str) -> Result<String, IfTError> {
+    let mut out = String::with_capacity(document.len());
+    let mut rest = document;
+    while let Some(start) = rest.find(OPEN) {
+        out.push_str(&rest[..start]);
+        if rest[start..].starts_with(ESCAPED_OPEN) {
+            out.push_str(OPEN);
+            rest = &rest[start + ESCAPED_OPEN.len()..];
+            continue;
+        }
+        let line = document[..document.len() - rest.len() + start].matches('\n').count() + 1;
+        let after_open = &rest[start + OPEN.len()..];
+        let end = match after_open.find(CLOSE) {
+            Some(end) => end,
+            None => Err(IfTError::IfTRenderError {
+                line,
+                message: format!("missing closing {}", CLOSE),
+            })?,
+        };
+        let template = after_open[..end].trim();
+        let ips = eval_detailed(template).map_err(|e| IfTError::IfTRenderError {
+            line,
+            message: format!("unable to evaluate [{}]: {}", template, e),
+        })?;
+        let ips: Vec<String> = ips.iter().map(IpInterface::to_zoned_string).collect();
+        out.push_str(&ips.join(" "));
+        rest = &after_open[end + CLOSE.len()..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{render, IfTError};
+
+    #[test]
+    fn test_render_without_templates() {
+        let document = "a = 1\n{ b = { c = 2 } }\n";
+        assert_eq!(document, render(document).unwrap());
+    }
+
+    #[test]
+    fn test_render_escaped_open() {
+        assert_eq!("{{ a }} {{{{", render("{{{{ a }} {{{{{{{{").unwrap());
+        assert_eq!(
+            "{{127.0.0.1}}",
+            render(r#"{{{{{{ GetAllInterfaces | FilterIPv4 | FilterScope "link-local" | FilterFirst }}}}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_render_empty_result() {
+        assert_eq!("bind = \"\"", render(r#"bind = "{{GetInterface "nothere"}}""#).unwrap());
+    }
+
+    #[test]
+    fn test_render_errors_have_lines() {
+        let err = render("a\nb {{ GetAllInterfaces").unwrap_err();
+        match err {
+            IfTError::IfTRenderError { line: 2, .. } => (),
+            _ => panic!("expected an error on line 2, got {}", err),
+        }
+        let err = render("a\n\nb {{ GetNothing }}").unwrap_err();
+        match err {
+            IfTError::IfTRenderError { line: 3, .. } => (),
+            _ => panic!("expected an error on line 3, got {}", err),
+        }
+    }
+}
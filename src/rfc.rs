@@ -1,24 +1,35 @@
 //! to get specific information about rfcs used by the templates
+use crate::IfTError;
 use ipnet::IpNet;
-use std::net::IpAddr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt, net::IpAddr, str::FromStr};
 
+#[rustfmt::skip]
+mod rfc4291_entries;
+#[rustfmt::skip]
+mod rfc5771_entries;
+#[rustfmt::skip]
 mod rfc6890_entries;
 
 /// Entry containing everything from the table specified in
 /// [RFC6890](https://tools.ietf.org/rfc/rfc6890.txt)
 ///
-#[derive(Debug)]
+/// With the `serde` feature entries can be serialized and deserialized. The static table
+/// borrows its strings, deserialized entries own them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Rfc6890Entry {
     /// IpNet block
     pub address_block: IpNet,
     /// Name from RFC
-    pub name: String,
+    pub name: Cow<'static, str>,
     /// Original RFC
-    pub rfc: String,
+    pub rfc: Cow<'static, str>,
     /// Allocation Date
-    pub allocation_date: String,
-    /// If terminated by when
-    pub termination_date: String,
+    pub allocation_date: Cow<'static, str>,
+    /// When the entry was terminated, if it has been
+    pub terminated: Option<Date>,
     /// Is a source
     pub source: bool,
     /// Is a destination
@@ -29,19 +40,59 @@ pub struct Rfc6890Entry {
     pub global: bool,
     /// Is reserved
     pub reserved_by_protocol: bool,
+    /// Footnotes qualifying the attributes above, prefixed by the attributes they apply to.
+    /// e.g. `forwardable, global: Unless allowed by a more specific allocation.`
+    pub notes: Cow<'static, [Cow<'static, str>]>,
+}
+
+impl Rfc6890Entry {
+    /// Whether or not the entry has a termination date
+    pub fn is_terminated(&self) -> bool {
+        self.terminated.is_some()
+    }
+}
+
+/// Year and month, which is the granularity that the registries record dates in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Date {
+    /// Year
+    pub year: u16,
+    /// Month, starting at 1
+    pub month: u8,
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
 }
 
 /// Used to check IpAddr's against all the rfc 6890 entries and find the one that
 /// matches the most specific definition
 pub struct WithRfc6890 {
-    /// vector of all of the available entries
-    pub entries: Vec<Rfc6890Entry>,
+    /// static table of all of the available entries
+    pub entries: &'static [Rfc6890Entry],
+    /// Whether terminated entries are still used when matching an IpAddr. Off by default
+    /// ```
+    /// use ift::rfc::WithRfc6890;
+    /// let rfc = WithRfc6890::create();
+    /// assert_eq!(true, rfc.is_global(&"192.88.99.1".parse().unwrap()), "6to4 relay anycast was terminated");
+    ///
+    /// let rfc = WithRfc6890 { include_terminated: true, ..WithRfc6890::create() };
+    /// assert_eq!(false, rfc.is_global(&"192.88.99.1".parse().unwrap()));
+    /// ```
+    pub include_terminated: bool,
 }
 
 impl WithRfc6890 {
-    /// Build the WithRfc6890, by creating the list of Rfc6890Entry's
-    pub fn create() -> WithRfc6890 {
-        rfc6890_entries::entries()
+    /// Build the WithRfc6890, borrowing the generated table of Rfc6890Entry's.
+    /// No allocation happens here, so this is cheap to call wherever it is needed.
+    pub const fn create() -> WithRfc6890 {
+        WithRfc6890 {
+            entries: rfc6890_entries::ENTRIES,
+            include_terminated: false,
+        }
     }
 
     /// RFC6890 https://tools.ietf.org/rfc/rfc6890.txt
@@ -101,21 +152,246 @@ impl WithRfc6890 {
         }
     }
 
-    fn find_most_specific(&self, ip: &IpAddr) -> Option<&Rfc6890Entry> {
-        let mut most_specific: Option<&Rfc6890Entry> = None;
-        for cur in &self.entries {
-            if cur.address_block.contains(ip) {
-                if let Some(existing) = most_specific {
-                    if existing.address_block.contains(&cur.address_block) {
-                        most_specific = Some(cur);
-                    }
-                } else {
+    /// The most specific entry that an IpAddr belongs to, or None if it is not special-purpose
+    pub fn find_most_specific(&self, ip: &IpAddr) -> Option<&Rfc6890Entry> {
+        self.find_most_specific_net(&IpNet::from(*ip))
+    }
+
+    /// The most specific entry that contains the whole IpNet, or None if there isn't one
+    ///
+    /// ```
+    /// use ift::rfc::WithRfc6890;
+    /// let rfc = WithRfc6890::create();
+    ///
+    /// assert_eq!("Shared Address Space", rfc.find_most_specific_net(&"100.64.1.0/24".parse().unwrap()).unwrap().name);
+    /// assert!(rfc.find_most_specific_net(&"100.0.0.0/8".parse().unwrap()).is_none());
+    /// ```
+    pub fn find_most_specific_net(&self, net: &IpNet) -> Option<&Rfc6890Entry> {
+        let entries = self
+            .entries
+            .iter()
+            .filter(|e| self.include_terminated || !e.is_terminated());
+        find_most_specific(entries, |e| &e.address_block, net)
+    }
+}
+
+/// Scope of an address, using the multicast scopes from
+/// [RFC4291](https://tools.ietf.org/rfc/rfc4291.txt) (updated by
+/// [RFC7346](https://tools.ietf.org/rfc/rfc7346.txt)) and the unicast scopes from
+/// [RFC4007](https://tools.ietf.org/rfc/rfc4007.txt). Ordered from smallest to largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "kebab-case"))]
+pub enum Scope {
+    /// interface-local, only within a single interface of a node
+    InterfaceLocal,
+    /// link-local
+    LinkLocal,
+    /// realm-local
+    RealmLocal,
+    /// admin-local, the smallest administratively configured scope
+    AdminLocal,
+    /// site-local
+    SiteLocal,
+    /// organization-local
+    OrganizationLocal,
+    /// global
+    Global,
+}
+
+impl Scope {
+    /// Name used in templates, e.g. `FilterScope "link-local"`
+    pub fn name(self) -> &'static str {
+        match self {
+            Scope::InterfaceLocal => "interface-local",
+            Scope::LinkLocal => "link-local",
+            Scope::RealmLocal => "realm-local",
+            Scope::AdminLocal => "admin-local",
+            Scope::SiteLocal => "site-local",
+            Scope::OrganizationLocal => "organization-local",
+            Scope::Global => "global",
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = IfTError;
+
+    fn from_str(scope: &str) -> ::std::result::Result<Self, Self::Err> {
+        match scope {
+            "interface-local" => Ok(Scope::InterfaceLocal),
+            "link-local" => Ok(Scope::LinkLocal),
+            "realm-local" => Ok(Scope::RealmLocal),
+            "admin-local" => Ok(Scope::AdminLocal),
+            "site-local" => Ok(Scope::SiteLocal),
+            "organization-local" => Ok(Scope::OrganizationLocal),
+            "global" => Ok(Scope::Global),
+            _ => Err(IfTError::IfTArgumentError(scope.to_owned())),
+        }
+    }
+}
+
+/// Scope of any IpAddr.
+///
+/// IPv6 uses [WithRfc4291](struct.WithRfc4291.html) and IPv4 multicast uses
+/// [WithRfc5771](struct.WithRfc5771.html). IPv4 unicast has no scoped architecture, so the
+/// loopback and link-local (RFC3927) blocks are treated as link-local the same way RFC4007
+/// treats `::1`, and everything else is global.
+///
+/// ```
+/// use ift::rfc::{scope, Scope};
+///
+/// assert_eq!(Scope::LinkLocal, scope(&"fe80::1".parse().unwrap()));
+/// assert_eq!(Scope::SiteLocal, scope(&"ff05::2".parse().unwrap()));
+/// assert_eq!(Scope::Global, scope(&"2001:4860:4860::8844".parse().unwrap()));
+/// assert_eq!(Scope::LinkLocal, scope(&"224.0.0.251".parse().unwrap()), "mdns");
+/// assert_eq!(Scope::SiteLocal, scope(&"239.255.255.250".parse().unwrap()), "ssdp");
+/// assert_eq!(Scope::LinkLocal, scope(&"169.254.169.254".parse().unwrap()));
+/// assert_eq!(Scope::Global, scope(&"192.168.1.100".parse().unwrap()));
+/// ```
+pub fn scope(ip: &IpAddr) -> Scope {
+    match ip {
+        IpAddr::V6(_) => WithRfc4291::create().scope(ip).unwrap_or(Scope::Global),
+        IpAddr::V4(ipv4) => match WithRfc5771::create().scope(ip) {
+            Some(scope) => scope,
+            None if ipv4.is_loopback() || ipv4.is_link_local() => Scope::LinkLocal,
+            None => Scope::Global,
+        },
+    }
+}
+
+/// Entry describing the scope of an IPv6 address block from
+/// [RFC4291](https://tools.ietf.org/rfc/rfc4291.txt)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rfc4291Entry {
+    /// IpNet block
+    pub address_block: IpNet,
+    /// Name of the block
+    pub name: Cow<'static, str>,
+    /// RFC defining the block
+    pub rfc: Cow<'static, str>,
+    /// Scope of addresses in the block
+    pub scope: Scope,
+}
+
+/// Used to find the scope of IPv6 addresses, from the most specific matching entry
+pub struct WithRfc4291 {
+    /// static table of all of the available entries
+    pub entries: &'static [Rfc4291Entry],
+}
+
+impl WithRfc4291 {
+    /// Build the WithRfc4291, borrowing the generated table of Rfc4291Entry's.
+    pub const fn create() -> WithRfc4291 {
+        WithRfc4291 {
+            entries: rfc4291_entries::ENTRIES,
+        }
+    }
+
+    /// RFC4291 https://tools.ietf.org/rfc/rfc4291.txt
+    ///
+    /// Scope of an IPv6 address. Multicast addresses use their scop field, unicast
+    /// addresses are link-local, site-local (deprecated by RFC3879) or global.
+    /// Returns None for IPv4 addresses.
+    ///
+    /// ```
+    /// use ift::rfc::{Scope, WithRfc4291};
+    /// let rfc = WithRfc4291::create();
+    ///
+    /// assert_eq!(Some(Scope::InterfaceLocal), rfc.scope(&"ff01::1".parse().unwrap()));
+    /// assert_eq!(Some(Scope::LinkLocal), rfc.scope(&"ff32::1".parse().unwrap()), "flags are ignored");
+    /// assert_eq!(Some(Scope::LinkLocal), rfc.scope(&"::1".parse().unwrap()), "loopback");
+    /// assert_eq!(Some(Scope::Global), rfc.scope(&"fd00::1".parse().unwrap()), "unique local");
+    /// assert_eq!(None, rfc.scope(&"127.0.0.1".parse().unwrap()));
+    /// ```
+    pub fn scope(&self, ip: &IpAddr) -> Option<Scope> {
+        if !ip.is_ipv6() {
+            return None;
+        }
+        match find_most_specific(self.entries.iter(), |e| &e.address_block, &IpNet::from(*ip)) {
+            Some(entry) => Some(entry.scope),
+            None => Some(Scope::Global),
+        }
+    }
+}
+
+/// Entry describing an IPv4 multicast address block from
+/// [RFC5771](https://tools.ietf.org/rfc/rfc5771.txt), and the administratively scoped
+/// blocks of [RFC2365](https://tools.ietf.org/rfc/rfc2365.txt)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rfc5771Entry {
+    /// IpNet block
+    pub address_block: IpNet,
+    /// Designation of the block
+    pub name: Cow<'static, str>,
+    /// RFC defining the block
+    pub rfc: Cow<'static, str>,
+    /// Scope of addresses in the block
+    pub scope: Scope,
+}
+
+/// Used to classify IPv4 multicast addresses, from the most specific matching entry
+pub struct WithRfc5771 {
+    /// static table of all of the available entries
+    pub entries: &'static [Rfc5771Entry],
+}
+
+impl WithRfc5771 {
+    /// Build the WithRfc5771, borrowing the generated table of Rfc5771Entry's.
+    pub const fn create() -> WithRfc5771 {
+        WithRfc5771 {
+            entries: rfc5771_entries::ENTRIES,
+        }
+    }
+
+    /// RFC5771 https://tools.ietf.org/rfc/rfc5771.txt
+    ///
+    /// Scope of an IPv4 multicast address. Returns None for anything that is not IPv4 multicast.
+    ///
+    /// ```
+    /// use ift::rfc::{Scope, WithRfc5771};
+    /// let rfc = WithRfc5771::create();
+    ///
+    /// assert_eq!(Some(Scope::LinkLocal), rfc.scope(&"224.0.0.1".parse().unwrap()));
+    /// assert_eq!(Some(Scope::OrganizationLocal), rfc.scope(&"239.192.0.1".parse().unwrap()));
+    /// assert_eq!(Some(Scope::Global), rfc.scope(&"232.1.1.1".parse().unwrap()), "ssm");
+    /// assert_eq!(None, rfc.scope(&"10.0.0.1".parse().unwrap()));
+    /// ```
+    pub fn scope(&self, ip: &IpAddr) -> Option<Scope> {
+        self.find_most_specific(ip).map(|e| e.scope)
+    }
+
+    /// The most specific block an IPv4 multicast address belongs to
+    pub fn find_most_specific(&self, ip: &IpAddr) -> Option<&Rfc5771Entry> {
+        find_most_specific(self.entries.iter(), |e| &e.address_block, &IpNet::from(*ip))
+    }
+}
+
+fn find_most_specific<'a, T>(
+    entries: impl Iterator<Item = &'a T>,
+    address_block: impl Fn(&T) -> &IpNet,
+    net: &IpNet,
+) -> Option<&'a T> {
+    let mut most_specific: Option<&'a T> = None;
+    for cur in entries {
+        if address_block(cur).contains(net) {
+            if let Some(existing) = most_specific {
+                if address_block(existing).contains(address_block(cur)) {
                     most_specific = Some(cur);
                 }
+            } else {
+                most_specific = Some(cur);
             }
         }
-        most_specific
     }
+    most_specific
 }
 
 #[cfg(test)]
@@ -129,10 +405,14 @@ mod tests {
     #[test]
     fn is_forwardable_or_not_192() {
         let all: IpNet = "192.0.0.0/24".parse().unwrap();
-        let specific: IpNet = "192.0.0.0/29".parse().unwrap();
+        let specific: Vec<IpNet> = vec![
+            "192.0.0.0/29".parse().unwrap(),
+            "192.0.0.9/32".parse().unwrap(),
+            "192.0.0.10/32".parse().unwrap(),
+        ];
         let rfc = WithRfc6890::create();
         for ip_addr in all.hosts() {
-            let is_forwardable = specific.contains(&ip_addr);
+            let is_forwardable = specific.iter().any(|net| net.contains(&ip_addr));
             assert_eq!(
                 is_forwardable,
                 rfc.is_forwardable(&ip_addr),
@@ -142,14 +422,55 @@ mod tests {
         }
     }
 
-    // check 192 for global
+    // check 192 for global. the 6to4 relay anycast block was deprecated, except
+    // for the 6a44 relay which is not global
     #[test]
     fn is_global_192() {
         let all: IpNet = "192.88.99.0/24".parse().unwrap();
+        let relay: IpAddr = "192.88.99.2".parse().unwrap();
         let rfc = WithRfc6890::create();
         for ip_addr in all.hosts() {
+            assert_eq!(ip_addr != relay, rfc.is_global(&ip_addr), "failure on ip {}", ip_addr)
+        }
+    }
+
+    // entries added to the IANA registries after RFC 6890 was published
+    #[test]
+    fn is_global_iana_updates() {
+        let rfc = WithRfc6890::create();
+        for ip in &[
+            "192.31.196.1",
+            "192.52.193.1",
+            "192.175.48.1",
+            "2001:1::1",
+            "2001:4:112::1",
+            "2620:4f:8000::1",
+        ] {
+            let ip_addr: IpAddr = ip.parse().unwrap();
             assert!(rfc.is_global(&ip_addr), "failure on ip {}", ip_addr)
         }
+        for ip in &["64:ff9b:1::1", "5f00::1"] {
+            let ip_addr: IpAddr = ip.parse().unwrap();
+            assert!(!rfc.is_global(&ip_addr), "failure on ip {}", ip_addr)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        use crate::rfc::{Rfc6890Entry, Scope};
+
+        let rfc = WithRfc6890::create();
+        let entry = serde_json::to_value(&rfc.entries[0]).unwrap();
+        assert_eq!("0.0.0.0/8", entry["address_block"]);
+        assert_eq!(false, entry["forwardable"]);
+        for e in rfc.entries.iter() {
+            let json = serde_json::to_string(e).unwrap();
+            assert_eq!(*e, serde_json::from_str::<Rfc6890Entry>(&json).unwrap());
+        }
+
+        let scope: Scope = serde_json::from_str(r#""site-local""#).unwrap();
+        assert_eq!(Scope::SiteLocal, scope);
     }
 
     #[test]
@@ -0,0 +1,698 @@
+// generated by ift-gen from rfc4291_scopes.csv. do not edit.
+use crate::rfc::{Rfc4291Entry, Scope};
+use ipnet::IpNet;
+use std::borrow::Cow;
+use std::net::{IpAddr, Ipv6Addr};
+
+pub static ENTRIES: &[Rfc4291Entry] = &[
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1)), 128),
+        name: Cow::Borrowed("Loopback Address"),
+        rfc: Cow::Borrowed("[RFC4007]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfe80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 10),
+        name: Cow::Borrowed("Link-Local Unicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfec0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 10),
+        name: Cow::Borrowed("Site-Local Unicast (deprecated)"),
+        rfc: Cow::Borrowed("[RFC3879]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff01, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff11, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff21, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff31, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff41, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff51, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff61, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff71, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff81, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff91, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffa1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffb1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffc1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffd1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffe1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfff1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Interface-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::InterfaceLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff02, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff12, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff22, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff32, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff42, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff52, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff62, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff72, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff82, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff92, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffa2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffb2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffc2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffd2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffe2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfff2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Link-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff03, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff13, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff23, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff33, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff43, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff53, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff63, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff73, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff83, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff93, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffa3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffb3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffc3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffd3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffe3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfff3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Realm-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC7346]"),
+        scope: Scope::RealmLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff04, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff14, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff24, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff34, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff44, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff54, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff64, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff74, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff84, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff94, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffa4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffb4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffc4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffd4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffe4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfff4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Admin-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff05, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff15, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff25, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff35, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff45, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff55, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff65, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff75, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff85, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff95, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffa5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffb5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffc5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffd5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffe5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfff5, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Site-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::SiteLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff08, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff18, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff28, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff38, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff48, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff58, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff68, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff78, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff88, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff98, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffa8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffb8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffc8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffd8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffe8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfff8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Organization-Local Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff0e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff1e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff2e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff3e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff4e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff5e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff6e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff7e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff8e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xff9e, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffae, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffbe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffce, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffde, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xffee, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+    Rfc4291Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfffe, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Global Multicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        scope: Scope::Global,
+    },
+];
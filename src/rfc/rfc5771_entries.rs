@@ -0,0 +1,236 @@
+// generated by ift-gen from rfc5771_blocks.csv. do not edit.
+use crate::rfc::{Rfc5771Entry, Scope};
+use ipnet::IpNet;
+use std::borrow::Cow;
+use std::net::{IpAddr, Ipv4Addr};
+
+pub static ENTRIES: &[Rfc5771Entry] = &[
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 0, 0)), 24),
+        name: Cow::Borrowed("Local Network Control Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::LinkLocal,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 1, 0)), 24),
+        name: Cow::Borrowed("Internetwork Control Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 2, 0)), 23),
+        name: Cow::Borrowed("AD-HOC Block I"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 4, 0)), 22),
+        name: Cow::Borrowed("AD-HOC Block I"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 8, 0)), 21),
+        name: Cow::Borrowed("AD-HOC Block I"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 16, 0)), 20),
+        name: Cow::Borrowed("AD-HOC Block I"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 32, 0)), 19),
+        name: Cow::Borrowed("AD-HOC Block I"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 64, 0)), 18),
+        name: Cow::Borrowed("AD-HOC Block I"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 0, 128, 0)), 17),
+        name: Cow::Borrowed("AD-HOC Block I"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 1, 0, 0)), 16),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 2, 0, 0)), 16),
+        name: Cow::Borrowed("SDP/SAP Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 3, 0, 0)), 16),
+        name: Cow::Borrowed("AD-HOC Block II"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 4, 0, 0)), 16),
+        name: Cow::Borrowed("AD-HOC Block II"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 5, 0, 0)), 16),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 6, 0, 0)), 15),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 8, 0, 0)), 13),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 16, 0, 0)), 12),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 32, 0, 0)), 11),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 64, 0, 0)), 10),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(224, 128, 0, 0)), 9),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(225, 0, 0, 0)), 8),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(226, 0, 0, 0)), 7),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(228, 0, 0, 0)), 6),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(232, 0, 0, 0)), 8),
+        name: Cow::Borrowed("Source-Specific Multicast Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(233, 0, 0, 0)), 9),
+        name: Cow::Borrowed("GLOP Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(233, 128, 0, 0)), 10),
+        name: Cow::Borrowed("GLOP Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(233, 192, 0, 0)), 11),
+        name: Cow::Borrowed("GLOP Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(233, 224, 0, 0)), 12),
+        name: Cow::Borrowed("GLOP Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(233, 240, 0, 0)), 13),
+        name: Cow::Borrowed("GLOP Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(233, 248, 0, 0)), 14),
+        name: Cow::Borrowed("GLOP Block"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(233, 252, 0, 0)), 14),
+        name: Cow::Borrowed("AD-HOC Block III"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(234, 0, 0, 0)), 8),
+        name: Cow::Borrowed("Unicast-Prefix-based IPv4 Multicast Addresses"),
+        rfc: Cow::Borrowed("[RFC6034]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(235, 0, 0, 0)), 8),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(236, 0, 0, 0)), 7),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(238, 0, 0, 0)), 8),
+        name: Cow::Borrowed("RESERVED"),
+        rfc: Cow::Borrowed("[RFC5771]"),
+        scope: Scope::Global,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(239, 0, 0, 0)), 8),
+        name: Cow::Borrowed("Administratively Scoped Block"),
+        rfc: Cow::Borrowed("[RFC2365]"),
+        scope: Scope::AdminLocal,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(239, 192, 0, 0)), 14),
+        name: Cow::Borrowed("IPv4 Organization Local Scope"),
+        rfc: Cow::Borrowed("[RFC2365]"),
+        scope: Scope::OrganizationLocal,
+    },
+    Rfc5771Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(239, 255, 0, 0)), 16),
+        name: Cow::Borrowed("IPv4 Local Scope"),
+        rfc: Cow::Borrowed("[RFC2365]"),
+        scope: Scope::SiteLocal,
+    },
+];
@@ -1,355 +1,647 @@
-use crate::rfc::{Rfc6890Entry, WithRfc6890};
-pub fn entries() -> WithRfc6890 {
-    WithRfc6890 {
-        entries: vec![
-            Rfc6890Entry {
-                address_block: "0.0.0.0/8".parse().unwrap(),
-                name: "\"This host on this network".to_owned(),
-                rfc: "[RFC1122], Section 3.2.1.3".to_owned(),
-                allocation_date: "September 1981".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-            Rfc6890Entry {
-                address_block: "10.0.0.0/8".parse().unwrap(),
-                name: "Private-Use".to_owned(),
-                rfc: "[RFC1918]".to_owned(),
-                allocation_date: "February 1996".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "100.64.0.0/10".parse().unwrap(),
-                name: "Shared Address Space".to_owned(),
-                rfc: "[RFC6598]".to_owned(),
-                allocation_date: "April 2012".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "127.0.0.0/8".parse().unwrap(),
-                name: "Loopback".to_owned(),
-                rfc: "[RFC1122], Section 3.2.1.3".to_owned(),
-                allocation_date: "September 1981".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-            Rfc6890Entry {
-                address_block: "169.254.0.0/16".parse().unwrap(),
-                name: "Link Local".to_owned(),
-                rfc: "[RFC3927]".to_owned(),
-                allocation_date: "May 2005".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-            Rfc6890Entry {
-                address_block: "172.16.0.0/12".parse().unwrap(),
-                name: "Private-Use".to_owned(),
-                rfc: "[RFC1918]".to_owned(),
-                allocation_date: "February 1996".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "192.0.0.0/24".parse().unwrap(),
-                name: "IETF Protocol Assignments".to_owned(),
-                rfc: "Section 2.1 of this document".to_owned(),
-                allocation_date: "January 2010".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "192.0.0.0/29".parse().unwrap(),
-                name: "DS-Lite".to_owned(),
-                rfc: "[RFC6333]".to_owned(),
-                allocation_date: "June 2011".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "192.0.2.0/24".parse().unwrap(),
-                name: "Documentation (TEST-NET-1)".to_owned(),
-                rfc: "[RFC5737]".to_owned(),
-                allocation_date: "January 2010".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "192.88.99.0/24".parse().unwrap(),
-                name: "6to4 Relay Anycast".to_owned(),
-                rfc: "[RFC3068]".to_owned(),
-                allocation_date: "June 2001".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: true,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "192.168.0.0/16".parse().unwrap(),
-                name: "Private-Use".to_owned(),
-                rfc: "[RFC1918]".to_owned(),
-                allocation_date: "February 1996".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "198.18.0.0/15".parse().unwrap(),
-                name: "Benchmarking".to_owned(),
-                rfc: "[RFC2544]".to_owned(),
-                allocation_date: "March 1999".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "198.51.100.0/24".parse().unwrap(),
-                name: "Documentation (TEST-NET-2)".to_owned(),
-                rfc: "[RFC5737]".to_owned(),
-                allocation_date: "January 2010".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "203.0.113.0/24".parse().unwrap(),
-                name: "Documentation (TEST-NET-3)".to_owned(),
-                rfc: "[RFC5737]".to_owned(),
-                allocation_date: "January 2010".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "240.0.0.0/4".parse().unwrap(),
-                name: "Reserved".to_owned(),
-                rfc: "[RFC1112], Section 4".to_owned(),
-                allocation_date: "August 1989".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-            Rfc6890Entry {
-                address_block: "255.255.255.255/32".parse().unwrap(),
-                name: "Limited Broadcast".to_owned(),
-                rfc: "[RFC0919], Section 7".to_owned(),
-                allocation_date: "October 1984".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: true,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "::1/128".parse().unwrap(),
-                name: "Loopback Address".to_owned(),
-                rfc: "[RFC4291]".to_owned(),
-                allocation_date: "February 2006".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-            Rfc6890Entry {
-                address_block: "::/128".parse().unwrap(),
-                name: "Unspecified Address".to_owned(),
-                rfc: "[RFC4291]".to_owned(),
-                allocation_date: "February 2006".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-            Rfc6890Entry {
-                address_block: "64:ff9b::/96".parse().unwrap(),
-                name: "IPv4-IPv6 Translat.".to_owned(),
-                rfc: "[RFC6052]".to_owned(),
-                allocation_date: "October 2010".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: true,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "::ffff:0.0.0.0/96".parse().unwrap(),
-                name: "IPv4-mapped Address".to_owned(),
-                rfc: "[RFC4291]".to_owned(),
-                allocation_date: "February 2006".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-            Rfc6890Entry {
-                address_block: "100::/64".parse().unwrap(),
-                name: "Discard-Only Address Block".to_owned(),
-                rfc: "[RFC6666]".to_owned(),
-                allocation_date: "June 2012".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "2001::/23".parse().unwrap(),
-                name: "IETF Protocol Assignments".to_owned(),
-                rfc: "[RFC2928]".to_owned(),
-                allocation_date: "September 2000".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "2001::/32".parse().unwrap(),
-                name: "TEREDO".to_owned(),
-                rfc: "[RFC4380]".to_owned(),
-                allocation_date: "January 2006".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "2001:2::/48".parse().unwrap(),
-                name: "Benchmarking".to_owned(),
-                rfc: "[RFC5180]".to_owned(),
-                allocation_date: "April 2008".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "2001:db8::/32".parse().unwrap(),
-                name: "Documentation".to_owned(),
-                rfc: "[RFC3849]".to_owned(),
-                allocation_date: "July 2004".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            /*Rfc6890Entry {
-                address_block: "2001:10::/28".parse().unwrap(),
-                name: "ORCHID".to_owned(),
-                rfc: "[RFC4843]".to_owned(),
-                allocation_date: "March 2007".to_owned(),
-                termination_date: "March 2014".to_owned(),
-                source: false,
-                destination: false,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: false
-            },*/
-            Rfc6890Entry {
-                address_block: "2002::/16".parse().unwrap(),
-                name: "6to4".to_owned(),
-                rfc: "[RFC3056]".to_owned(),
-                allocation_date: "February 2001".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "fc00::/7".parse().unwrap(),
-                name: "Unique-Local".to_owned(),
-                rfc: "[RFC4193]".to_owned(),
-                allocation_date: "October 2005".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: true,
-                global: false,
-                reserved_by_protocol: false,
-            },
-            Rfc6890Entry {
-                address_block: "fe80::/10".parse().unwrap(),
-                name: "Linked-Scoped Unicast".to_owned(),
-                rfc: "[RFC4291]".to_owned(),
-                allocation_date: "February 2006".to_owned(),
-                termination_date: "N/A".to_owned(),
-                source: true,
-                destination: true,
-                forwardable: false,
-                global: false,
-                reserved_by_protocol: true,
-            },
-        ],
-    }
-}
+// generated by ift-gen from the IANA special-purpose address registries. do not edit.
+use crate::rfc::{Date, Rfc6890Entry};
+use ipnet::IpNet;
+use std::{
+    borrow::Cow,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+pub static ENTRIES: &[Rfc6890Entry] = &[
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 8),
+        name: Cow::Borrowed("\"This network\""),
+        rfc: Cow::Borrowed("[RFC791], Section 3.2"),
+        allocation_date: Cow::Borrowed("1981-09"),
+        terminated: None,
+        source: true,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 32),
+        name: Cow::Borrowed("\"This host on this network\""),
+        rfc: Cow::Borrowed("[RFC1122], Section 3.2.1.3"),
+        allocation_date: Cow::Borrowed("1981-09"),
+        terminated: None,
+        source: true,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8),
+        name: Cow::Borrowed("Private-Use"),
+        rfc: Cow::Borrowed("[RFC1918]"),
+        allocation_date: Cow::Borrowed("1996-02"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(100, 64, 0, 0)), 10),
+        name: Cow::Borrowed("Shared Address Space"),
+        rfc: Cow::Borrowed("[RFC6598]"),
+        allocation_date: Cow::Borrowed("2012-04"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 0)), 8),
+        name: Cow::Borrowed("Loopback"),
+        rfc: Cow::Borrowed("[RFC1122], Section 3.2.1.3"),
+        allocation_date: Cow::Borrowed("1981-09"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[Cow::Borrowed("source, destination, forwardable, global: Several protocols have been granted exceptions to this rule. For examples, see [RFC4379] and [RFC5884].")]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(169, 254, 0, 0)), 16),
+        name: Cow::Borrowed("Link Local"),
+        rfc: Cow::Borrowed("[RFC3927]"),
+        allocation_date: Cow::Borrowed("2005-05"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(172, 16, 0, 0)), 12),
+        name: Cow::Borrowed("Private-Use"),
+        rfc: Cow::Borrowed("[RFC1918]"),
+        allocation_date: Cow::Borrowed("1996-02"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 0, 0)), 24),
+        name: Cow::Borrowed("IETF Protocol Assignments"),
+        rfc: Cow::Borrowed("[RFC6890], Section 2.1"),
+        allocation_date: Cow::Borrowed("2010-01"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[Cow::Borrowed("address_block: Not useable unless by virtue of a more specific reservation.")]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 0, 0)), 29),
+        name: Cow::Borrowed("IPv4 Service Continuity Prefix"),
+        rfc: Cow::Borrowed("[RFC7335]"),
+        allocation_date: Cow::Borrowed("2011-06"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 0, 8)), 32),
+        name: Cow::Borrowed("IPv4 dummy address"),
+        rfc: Cow::Borrowed("[RFC7600]"),
+        allocation_date: Cow::Borrowed("2015-03"),
+        terminated: None,
+        source: true,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 0, 9)), 32),
+        name: Cow::Borrowed("Port Control Protocol Anycast"),
+        rfc: Cow::Borrowed("[RFC7723]"),
+        allocation_date: Cow::Borrowed("2015-10"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 0, 10)), 32),
+        name: Cow::Borrowed("Traversal Using Relays around NAT Anycast"),
+        rfc: Cow::Borrowed("[RFC8155]"),
+        allocation_date: Cow::Borrowed("2017-02"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 0, 170)), 32),
+        name: Cow::Borrowed("NAT64/DNS64 Discovery"),
+        rfc: Cow::Borrowed("[RFC8880][RFC7050], Section 2.2"),
+        allocation_date: Cow::Borrowed("2013-02"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 0, 171)), 32),
+        name: Cow::Borrowed("NAT64/DNS64 Discovery"),
+        rfc: Cow::Borrowed("[RFC8880][RFC7050], Section 2.2"),
+        allocation_date: Cow::Borrowed("2013-02"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0)), 24),
+        name: Cow::Borrowed("Documentation (TEST-NET-1)"),
+        rfc: Cow::Borrowed("[RFC5737]"),
+        allocation_date: Cow::Borrowed("2010-01"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 31, 196, 0)), 24),
+        name: Cow::Borrowed("AS112-v4"),
+        rfc: Cow::Borrowed("[RFC7535]"),
+        allocation_date: Cow::Borrowed("2014-12"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 52, 193, 0)), 24),
+        name: Cow::Borrowed("AMT"),
+        rfc: Cow::Borrowed("[RFC7450]"),
+        allocation_date: Cow::Borrowed("2014-12"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 88, 99, 0)), 24),
+        name: Cow::Borrowed("Deprecated (6to4 Relay Anycast)"),
+        rfc: Cow::Borrowed("[RFC7526]"),
+        allocation_date: Cow::Borrowed("2001-06"),
+        terminated: Some(Date { year: 2015, month: 3 }),
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 88, 99, 2)), 32),
+        name: Cow::Borrowed("6a44-relay anycast address"),
+        rfc: Cow::Borrowed("[RFC6751]"),
+        allocation_date: Cow::Borrowed("2012-10"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 0)), 16),
+        name: Cow::Borrowed("Private-Use"),
+        rfc: Cow::Borrowed("[RFC1918]"),
+        allocation_date: Cow::Borrowed("1996-02"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(192, 175, 48, 0)), 24),
+        name: Cow::Borrowed("Direct Delegation AS112 Service"),
+        rfc: Cow::Borrowed("[RFC7534]"),
+        allocation_date: Cow::Borrowed("1996-01"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(198, 18, 0, 0)), 15),
+        name: Cow::Borrowed("Benchmarking"),
+        rfc: Cow::Borrowed("[RFC2544]"),
+        allocation_date: Cow::Borrowed("1999-03"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 0)), 24),
+        name: Cow::Borrowed("Documentation (TEST-NET-2)"),
+        rfc: Cow::Borrowed("[RFC5737]"),
+        allocation_date: Cow::Borrowed("2010-01"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(203, 0, 113, 0)), 24),
+        name: Cow::Borrowed("Documentation (TEST-NET-3)"),
+        rfc: Cow::Borrowed("[RFC5737]"),
+        allocation_date: Cow::Borrowed("2010-01"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(240, 0, 0, 0)), 4),
+        name: Cow::Borrowed("Reserved"),
+        rfc: Cow::Borrowed("[RFC1112], Section 4"),
+        allocation_date: Cow::Borrowed("1989-08"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255)), 32),
+        name: Cow::Borrowed("Limited Broadcast"),
+        rfc: Cow::Borrowed("[RFC8190] [RFC919], Section 7"),
+        allocation_date: Cow::Borrowed("1984-10"),
+        terminated: None,
+        source: false,
+        destination: true,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1)), 128),
+        name: Cow::Borrowed("Loopback Address"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        allocation_date: Cow::Borrowed("2006-02"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 128),
+        name: Cow::Borrowed("Unspecified Address"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        allocation_date: Cow::Borrowed("2006-02"),
+        terminated: None,
+        source: true,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x0, 0x0, 0x0, 0x0, 0x0, 0xffff, 0x0, 0x0)), 96),
+        name: Cow::Borrowed("IPv4-mapped Address"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        allocation_date: Cow::Borrowed("2006-02"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x64, 0xff9b, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 96),
+        name: Cow::Borrowed("IPv4-IPv6 Translat."),
+        rfc: Cow::Borrowed("[RFC6052]"),
+        allocation_date: Cow::Borrowed("2010-10"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x64, 0xff9b, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0)), 48),
+        name: Cow::Borrowed("IPv4-IPv6 Translat."),
+        rfc: Cow::Borrowed("[RFC8215]"),
+        allocation_date: Cow::Borrowed("2017-06"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x100, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 64),
+        name: Cow::Borrowed("Discard-Only Address Block"),
+        rfc: Cow::Borrowed("[RFC6666]"),
+        allocation_date: Cow::Borrowed("2012-06"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 23),
+        name: Cow::Borrowed("IETF Protocol Assignments"),
+        rfc: Cow::Borrowed("[RFC2928]"),
+        allocation_date: Cow::Borrowed("2000-09"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[Cow::Borrowed("source, destination, forwardable, global: Unless allowed by a more specific allocation.")]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 32),
+        name: Cow::Borrowed("TEREDO"),
+        rfc: Cow::Borrowed("[RFC4380] [RFC8190]"),
+        allocation_date: Cow::Borrowed("2006-01"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[Cow::Borrowed("global: See Section 5 of [RFC4380] for details.")]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1)), 128),
+        name: Cow::Borrowed("Port Control Protocol Anycast"),
+        rfc: Cow::Borrowed("[RFC7723]"),
+        allocation_date: Cow::Borrowed("2015-10"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2)), 128),
+        name: Cow::Borrowed("Traversal Using Relays around NAT Anycast"),
+        rfc: Cow::Borrowed("[RFC8155]"),
+        allocation_date: Cow::Borrowed("2017-02"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 48),
+        name: Cow::Borrowed("Benchmarking"),
+        rfc: Cow::Borrowed("[RFC5180][RFC Errata 1752]"),
+        allocation_date: Cow::Borrowed("2008-04"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x3, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 32),
+        name: Cow::Borrowed("AMT"),
+        rfc: Cow::Borrowed("[RFC7450]"),
+        allocation_date: Cow::Borrowed("2014-12"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x4, 0x112, 0x0, 0x0, 0x0, 0x0, 0x0)), 48),
+        name: Cow::Borrowed("AS112-v6"),
+        rfc: Cow::Borrowed("[RFC7535]"),
+        allocation_date: Cow::Borrowed("2014-12"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x10, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 28),
+        name: Cow::Borrowed("Deprecated (previously ORCHID)"),
+        rfc: Cow::Borrowed("[RFC4843]"),
+        allocation_date: Cow::Borrowed("2007-03"),
+        terminated: Some(Date { year: 2014, month: 3 }),
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x20, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 28),
+        name: Cow::Borrowed("ORCHIDv2"),
+        rfc: Cow::Borrowed("[RFC7343]"),
+        allocation_date: Cow::Borrowed("2014-07"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0x30, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 28),
+        name: Cow::Borrowed("Drone Remote ID Protocol Entity Tags (DETs) Prefix"),
+        rfc: Cow::Borrowed("[RFC9374]"),
+        allocation_date: Cow::Borrowed("2022-12"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 32),
+        name: Cow::Borrowed("Documentation"),
+        rfc: Cow::Borrowed("[RFC3849]"),
+        allocation_date: Cow::Borrowed("2004-07"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2002, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("6to4"),
+        rfc: Cow::Borrowed("[RFC3056]"),
+        allocation_date: Cow::Borrowed("2001-02"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[Cow::Borrowed("address_block, global: See [RFC3056] for details.")]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x2620, 0x4f, 0x8000, 0x0, 0x0, 0x0, 0x0, 0x0)), 48),
+        name: Cow::Borrowed("Direct Delegation AS112 Service"),
+        rfc: Cow::Borrowed("[RFC7534]"),
+        allocation_date: Cow::Borrowed("2011-05"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: true,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x3fff, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 20),
+        name: Cow::Borrowed("Documentation"),
+        rfc: Cow::Borrowed("[RFC9637]"),
+        allocation_date: Cow::Borrowed("2024-07"),
+        terminated: None,
+        source: false,
+        destination: false,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0x5f00, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 16),
+        name: Cow::Borrowed("Segment Routing (SRv6) SIDs"),
+        rfc: Cow::Borrowed("[RFC9602]"),
+        allocation_date: Cow::Borrowed("2024-04"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfc00, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 7),
+        name: Cow::Borrowed("Unique-Local"),
+        rfc: Cow::Borrowed("[RFC4193] [RFC8190]"),
+        allocation_date: Cow::Borrowed("2005-10"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: true,
+        global: false,
+        reserved_by_protocol: false,
+        notes: Cow::Borrowed(&[Cow::Borrowed("global: See [RFC4193] for more details on the routability of Unique-Local addresses. The Unique-Local prefix is drawn from the IPv6 Global Unicast Address range but is specified as not globally routed.")]),
+    },
+    Rfc6890Entry {
+        address_block: IpNet::new_assert(IpAddr::V6(Ipv6Addr::new(0xfe80, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0)), 10),
+        name: Cow::Borrowed("Link-Local Unicast"),
+        rfc: Cow::Borrowed("[RFC4291]"),
+        allocation_date: Cow::Borrowed("2006-02"),
+        terminated: None,
+        source: true,
+        destination: true,
+        forwardable: false,
+        global: false,
+        reserved_by_protocol: true,
+        notes: Cow::Borrowed(&[]),
+    },
+];
@@ -1,22 +1,67 @@
-use failure::Error;
-use std::process::Command;
+use crate::IfTError;
+use std::{io, process::Command, sync::Once};
 
-pub fn read_default_interface_name() -> Result<String, Error> {
+/// The `Warn` policy warns once per process, not on every evaluation or every poll of a `Watcher`
+static WARN_ONCE: Once = Once::new();
+
+/// What to do when the default route can not be looked up, like when the `ip` command is missing
+///
+/// Without a default route `SortBy "default"` and `GetPrivateInterfaces` keep the order the
+/// interfaces were listed in, unless the policy is `Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum DefaultRoutePolicy {
+    /// Fail the evaluation with `IfTError::IfTRouteError`
+    Error,
+    /// Print a warning to stderr, the first time the lookup fails in this process, and keep the
+    /// original order
+    #[default]
+    Warn,
+    /// Act as if there is no default route, and keep the original order
+    Ignore,
+}
+
+impl DefaultRoutePolicy {
+    /// Name of the interface the default route goes through, or `None` when it is unknown
+    pub(crate) fn lookup(self) -> Result<Option<String>, IfTError> {
+        match read_default_interface_name() {
+            Ok(name) => Ok(Some(name)),
+            Err(e) => match self {
+                DefaultRoutePolicy::Error => Err(IfTError::IfTRouteError(e)),
+                DefaultRoutePolicy::Warn => {
+                    WARN_ONCE.call_once(|| {
+                        eprintln!(
+                            "warning: unable to look up the default route, keeping the original order: {}",
+                            e
+                        )
+                    });
+                    Ok(None)
+                }
+                DefaultRoutePolicy::Ignore => Ok(None),
+            },
+        }
+    }
+}
+
+pub fn read_default_interface_name() -> io::Result<String> {
     if cfg!(target_os = "linux") {
-        Ok(parse_linux_ip_cmd(&String::from_utf8(
-            Command::new("ip").arg("route").output()?.stdout,
-        )?))
+        Ok(parse_linux_ip_cmd(&String::from_utf8_lossy(
+            &Command::new("ip").arg("route").output()?.stdout,
+        )))
     } else if cfg!(target_os = "macos") {
-        Ok(parse_mac_ip_cmd(&String::from_utf8(
-            Command::new("route")
+        Ok(parse_mac_ip_cmd(&String::from_utf8_lossy(
+            &Command::new("route")
                 .arg("-n")
                 .arg("get")
                 .arg("default")
                 .output()?
                 .stdout,
-        )?))
+        )))
     } else {
-        unimplemented!("unimplemented os")
+        Err(io::Error::other(
+            "looking up the default route is not supported on this os",
+        ))
     }
 }
 
@@ -34,7 +79,12 @@ fn parse_linux_ip_cmd(output: &str) -> String {
     for line in output.split('\n') {
         let line: &str = line.trim();
         if line.starts_with("default ") {
-            return line.split(' ').last().unwrap().to_owned();
+            // `default via 10.0.0.1 dev eth0 proto dhcp metric 100`, the interface follows `dev`
+            let mut words = line.split_whitespace();
+            return match words.position(|word| word == "dev") {
+                Some(_) => words.next().unwrap_or("").to_owned(),
+                None => "".to_owned(),
+            };
         }
     }
     "".to_owned()
@@ -65,4 +115,13 @@ destination: default
         172.17.0.0/16 dev eth0 scope link  src 172.17.0.16";
         assert_eq!("eth0", parse_linux_ip_cmd(out))
     }
+
+    #[test]
+    fn test_parse_linux_proto_metric() {
+        let out = "\
+default via 10.0.0.1 dev wlp2s0 proto dhcp src 10.0.0.23 metric 600
+10.0.0.0/24 dev wlp2s0 proto kernel scope link src 10.0.0.23 metric 600
+172.17.0.0/16 dev docker0 proto kernel scope link src 172.17.0.1 linkdown";
+        assert_eq!("wlp2s0", parse_linux_ip_cmd(out))
+    }
 }
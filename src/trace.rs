@@ -0,0 +1,154 @@
+//! What every stage of a template did, see `eval_traced`
+use crate::{
+    grammar::Rule,
+    rfc::{self, WithRfc6890},
+    IfTNetwork, IpInterface,
+};
+use pest::iterators::Pair;
+
+/// What one stage of a template did to the IpAddr's passing through it
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceStage {
+    /// The stage as written in the template, like `FilterFlags "up"`
+    pub stage: String,
+    /// What went into the stage. For the producer that is every address on every interface
+    pub input: Vec<IpInterface>,
+    /// What came out of the stage
+    pub output: Vec<IpInterface>,
+    /// What went into the stage but did not come out, and why
+    pub dropped: Vec<DroppedIp>,
+}
+
+/// An IpAddr that a stage of a template filtered out
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DroppedIp {
+    /// The IpAddr and the interface it came from
+    pub ip: IpInterface,
+    /// Why it was dropped, like "RFC 6890 Link-Local Unicast, forwardable=false"
+    pub reason: String,
+}
+
+impl TraceStage {
+    pub(crate) fn new(
+        pair: &Pair<'_, Rule>,
+        input: Vec<IpInterface>,
+        output: Vec<IpInterface>,
+        rfc: &WithRfc6890,
+    ) -> TraceStage {
+        let dropped = input
+            .iter()
+            .filter(|ip| !output.contains(ip))
+            .map(|ip| DroppedIp {
+                ip: ip.clone(),
+                reason: drop_reason(pair, ip, rfc),
+            })
+            .collect();
+        TraceStage {
+            stage: pair.as_str().to_owned(),
+            input,
+            output,
+            dropped,
+        }
+    }
+}
+
+fn drop_reason(pair: &Pair<'_, Rule>, ip: &IpInterface, rfc: &WithRfc6890) -> String {
+    let argument = pair.clone().into_inner().next().map_or("", |p| p.as_str());
+    let interface = ip.interface_name.as_ref().map_or("", String::as_str);
+    let is_up = ip.flags.iter().any(|flag| flag == "up");
+    match pair.as_rule() {
+        Rule::FilterIPv4 => "not an IPv4 address".to_owned(),
+        Rule::FilterIPv6 => "not an IPv6 address".to_owned(),
+        Rule::GetInterface | Rule::FilterName => format!("interface is {}, not {}", interface, argument),
+        Rule::FilterFlags if is_up => format!("interface {} is up", interface),
+        Rule::FilterFlags => format!("interface {} is down", interface),
+        Rule::FilterForwardable => rfc6890_reason(ip, rfc, "forwardable", rfc.is_forwardable(&ip.ip_addr)),
+        Rule::FilterGlobal => rfc6890_reason(ip, rfc, "global", rfc.is_global(&ip.ip_addr)),
+        Rule::GetPrivateInterfaces if !is_up => format!("interface {} is down", interface),
+        Rule::GetPrivateInterfaces => rfc6890_reason(ip, rfc, "forwardable", rfc.is_forwardable(&ip.ip_addr)),
+        Rule::FilterScope => format!("scope is {}, not {}", rfc::scope(&ip.ip_addr), argument),
+        Rule::FilterCIDR => match argument.parse::<IfTNetwork>() {
+            Ok(ref network) if network.net.contains(&ip.ip_addr) => format!(
+                "interface is {}, not {}",
+                interface,
+                network.zone.as_ref().map_or("", String::as_str)
+            ),
+            _ => format!("not in {}", argument),
+        },
+        Rule::FilterFirst => "not the first".to_owned(),
+        Rule::FilterLast => "not the last".to_owned(),
+        rule => format!("dropped by {:?}", rule),
+    }
+}
+
+fn rfc6890_reason(ip: &IpInterface, rfc: &WithRfc6890, attribute: &str, value: bool) -> String {
+    match rfc.find_most_specific(&ip.ip_addr) {
+        Some(entry) => format!("RFC 6890 {}, {}={}", entry.name, attribute, value),
+        None => format!("not special-purpose, {}={}", attribute, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval_traced;
+
+    #[test]
+    fn test_trace_every_stage() {
+        let trace = eval_traced(r#"GetAllInterfaces | FilterIPv4 | FilterName "lo" | SortBy "default""#).unwrap();
+        let stages: Vec<&str> = trace.iter().map(|t| t.stage.as_str()).collect();
+        assert_eq!(
+            vec![
+                "GetAllInterfaces",
+                "FilterIPv4",
+                r#"FilterName "lo""#,
+                r#"SortBy "default""#
+            ],
+            stages
+        );
+        assert_eq!(trace[0].input, trace[0].output);
+        for pair in trace.windows(2) {
+            assert_eq!(pair[0].output, pair[1].input);
+        }
+        for dropped in &trace[1].dropped {
+            assert!(dropped.ip.ip_addr.is_ipv6());
+            assert_eq!("not an IPv4 address", dropped.reason);
+        }
+        assert!(trace[3].dropped.is_empty());
+    }
+
+    #[test]
+    fn test_trace_producers_start_from_every_interface() {
+        let all = eval_traced("GetAllInterfaces").unwrap();
+        for template in &[r#"GetInterface "lo""#, "GetPrivateInterfaces"] {
+            let trace = eval_traced(template).unwrap();
+            assert_eq!(all[0].input, trace[0].input, "{}", template);
+            for dropped in &trace[0].dropped {
+                assert!(!trace[0].output.contains(&dropped.ip));
+            }
+            assert_eq!(trace[0].input.len(), trace[0].output.len() + trace[0].dropped.len());
+        }
+    }
+
+    #[test]
+    fn test_trace_forwardable_reason() {
+        let trace = eval_traced("GetAllInterfaces | FilterForwardable").unwrap();
+        let loopback = trace[1]
+            .dropped
+            .iter()
+            .find(|d| d.ip.ip_addr == "127.0.0.1".parse::<::std::net::IpAddr>().unwrap())
+            .expect("loopback is not forwardable");
+        assert_eq!("RFC 6890 Loopback, forwardable=false", loopback.reason);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_trace_round_trip() {
+        use crate::TraceStage;
+
+        let trace = eval_traced("GetAllInterfaces | FilterForwardable").unwrap();
+        let json = serde_json::to_string(&trace).unwrap();
+        assert_eq!(trace, serde_json::from_str::<Vec<TraceStage>>(&json).unwrap());
+    }
+}
@@ -0,0 +1,333 @@
+//! Re-evaluating templates as the interfaces change, see `wait_for` and `Watcher`
+//!
+//! On linux a netlink socket subscribed to link, address and route changes wakes the evaluator
+//! up as soon as something changes. Everywhere else, or when the socket can not be opened, the
+//! template is simply re-evaluated every poll interval.
+use crate::{eval, eval_detailed, IfTError, IpInterface, Template};
+use std::{
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+/// # Wait for a template to produce IpAddr's
+///
+/// Re-evaluates the template until it returns at least one IpAddr, checking again whenever
+/// the interfaces change or at least every `poll`. Fails with `IfTError::IfTTimeoutError`
+/// if nothing shows up within `timeout`.
+///
+/// ```
+/// use ift::wait_for;
+/// use std::time::Duration;
+/// let ips = wait_for("GetAllInterfaces", Duration::from_secs(5), Duration::from_millis(100)).unwrap();
+/// assert!(!ips.is_empty());
+/// ```
+pub fn wait_for(template: &str, timeout: Duration, poll: Duration) -> Result<Vec<IpAddr>, IfTError> {
+    let ips = wait_until(template, timeout, poll, |ips| !ips.is_empty())?;
+    if ips.is_empty() {
+        Err(IfTError::IfTTimeoutError(timeout))?
+    }
+    Ok(ips)
+}
+
+/// Just like `wait_for`, but waits until `done` accepts the result.
+///
+/// Returns the last result once `done` returns true or `timeout` has passed, so callers
+/// that need to know whether it timed out should check the result again.
+///
+/// ```
+/// use ift::wait_until;
+/// use std::time::Duration;
+/// let ips = wait_until("GetAllInterfaces | FilterIPv4", Duration::from_millis(100), Duration::from_millis(10), |ips| ips.len() >= 1).unwrap();
+/// assert!(ips.len() >= 1);
+/// ```
+pub fn wait_until<F>(template: &str, timeout: Duration, poll: Duration, done: F) -> Result<Vec<IpAddr>, IfTError>
+where
+    F: FnMut(&[IpAddr]) -> bool,
+{
+    wait(|| eval(template), timeout, poll, done)
+}
+
+/// Just like `wait_until`, but with the interface of every IpAddr, like `eval_detailed`. Use
+/// `IpInterface::to_zoned_string` to keep the zone of link-local IPv6 addresses.
+///
+/// ```
+/// use ift::wait_until_detailed;
+/// use std::time::Duration;
+/// let ips = wait_until_detailed("GetAllInterfaces", Duration::from_millis(100), Duration::from_millis(10), |ips| ips.len() >= 1).unwrap();
+/// for ip in ips {
+///     println!("{}", ip.to_zoned_string());
+/// }
+/// ```
+pub fn wait_until_detailed<F>(
+    template: &str,
+    timeout: Duration,
+    poll: Duration,
+    done: F,
+) -> Result<Vec<IpInterface>, IfTError>
+where
+    F: FnMut(&[IpInterface]) -> bool,
+{
+    wait(|| eval_detailed(template), timeout, poll, done)
+}
+
+fn wait<T, E, F>(evaluate: E, timeout: Duration, poll: Duration, mut done: F) -> Result<Vec<T>, IfTError>
+where
+    E: Fn() -> Result<Vec<T>, IfTError>,
+    F: FnMut(&[T]) -> bool,
+{
+    // a timeout too long to add to now is as good as no deadline at all
+    let deadline = Instant::now().checked_add(timeout);
+    // subscribe before the first evaluation so no change can slip in between
+    let changes = Changes::listen();
+    loop {
+        let ips = evaluate()?;
+        let left = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        if done(&ips) || left == Some(Duration::ZERO) {
+            return Ok(ips);
+        }
+        changes.wait(left.map_or(poll, |left| poll.min(left)));
+    }
+}
+
+/// # Watch a template for changes
+///
+/// An endless iterator that yields the result of the template right away, and then again
+/// every time the interfaces, addresses or routes change in a way that changes the result.
+/// Without change notifications from the os the template is evaluated every `poll`.
+///
+/// `Template::watch` yields IpAddr's, `Template::watch_detailed` yields `IpInterface`'s like
+/// `eval_detailed` does.
+///
+/// ```
+/// use ift::Template;
+/// use std::time::Duration;
+/// let template: Template = "GetAllInterfaces".parse().unwrap();
+/// for ips in template.watch(Duration::from_secs(1)).take(1) {
+///     println!("now bound to {:?}", ips.unwrap());
+/// }
+/// ```
+pub struct Watcher<T = IpAddr> {
+    template: Template,
+    evaluate: fn(&Template) -> Result<Vec<T>, IfTError>,
+    poll: Duration,
+    changes: Changes,
+    evaluated: bool,
+    last: Option<Vec<T>>,
+}
+
+impl Watcher {
+    /// Watch `template`, see `Template::watch`
+    pub fn new(template: Template, poll: Duration) -> Watcher {
+        Watcher::with(template, Template::eval, poll)
+    }
+}
+
+impl Watcher<IpInterface> {
+    /// Watch `template`, see `Template::watch_detailed`
+    pub fn new_detailed(template: Template, poll: Duration) -> Watcher<IpInterface> {
+        Watcher::with(template, Template::eval_detailed, poll)
+    }
+}
+
+impl<T> Watcher<T> {
+    fn with(template: Template, evaluate: fn(&Template) -> Result<Vec<T>, IfTError>, poll: Duration) -> Watcher<T> {
+        Watcher {
+            template,
+            evaluate,
+            poll,
+            changes: Changes::listen(),
+            evaluated: false,
+            last: None,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Iterator for Watcher<T> {
+    type Item = Result<Vec<T>, IfTError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.evaluated {
+                self.changes.wait(self.poll);
+            }
+            self.evaluated = true;
+            let ips = match (self.evaluate)(&self.template) {
+                Ok(ips) => ips,
+                Err(e) => return Some(Err(e)),
+            };
+            if self.last.as_ref() != Some(&ips) {
+                self.last = Some(ips.clone());
+                return Some(Ok(ips));
+            }
+        }
+    }
+}
+
+/// Wakes up when the interfaces change, as far as the os is able to tell us
+pub(crate) struct Changes {
+    #[cfg(target_os = "linux")]
+    socket: Option<netlink::Socket>,
+}
+
+impl Changes {
+    #[cfg(target_os = "linux")]
+    pub(crate) fn listen() -> Changes {
+        Changes {
+            socket: netlink::Socket::subscribe().ok(),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn listen() -> Changes {
+        Changes {}
+    }
+
+    /// Block until something changed or `timeout` has passed
+    pub(crate) fn wait(&self, timeout: Duration) {
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(ref socket) = self.socket {
+                if socket.wait(timeout).is_ok() {
+                    return;
+                }
+            }
+        }
+        ::std::thread::sleep(timeout)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod netlink {
+    use std::{io, mem, os::unix::io::RawFd, time::Duration};
+
+    const GROUPS: libc::c_int = libc::RTMGRP_LINK
+        | libc::RTMGRP_IPV4_IFADDR
+        | libc::RTMGRP_IPV6_IFADDR
+        | libc::RTMGRP_IPV4_ROUTE
+        | libc::RTMGRP_IPV6_ROUTE;
+
+    /// A rtnetlink socket subscribed to link, address and route notifications
+    pub struct Socket {
+        fd: RawFd,
+    }
+
+    impl Socket {
+        pub fn subscribe() -> io::Result<Socket> {
+            let fd = unsafe {
+                libc::socket(
+                    libc::AF_NETLINK,
+                    libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                    libc::NETLINK_ROUTE,
+                )
+            };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let socket = Socket { fd };
+            let mut addr: libc::sockaddr_nl = unsafe { mem::zeroed() };
+            addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+            addr.nl_groups = GROUPS as u32;
+            let bound = unsafe {
+                libc::bind(
+                    fd,
+                    &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                    mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+                )
+            };
+            if bound < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(socket)
+        }
+
+        /// Block until a notification arrives or `timeout` has passed, then drain the socket.
+        /// The messages themselves are not needed since the template is evaluated from scratch.
+        pub fn wait(&self, timeout: Duration) -> io::Result<()> {
+            let mut fds = libc::pollfd {
+                fd: self.fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let millis = timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+            if unsafe { libc::poll(&mut fds, 1, millis) } < 0 {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            let mut buf = [0u8; 8192];
+            loop {
+                let read = unsafe {
+                    libc::recv(
+                        self.fd,
+                        buf.as_mut_ptr() as *mut libc::c_void,
+                        buf.len(),
+                        libc::MSG_DONTWAIT,
+                    )
+                };
+                if read <= 0 {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    impl Drop for Socket {
+        fn drop(&mut self) {
+            unsafe {
+                libc::close(self.fd);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{wait_for, IfTError, Template};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_wait_for_times_out() {
+        let start = Instant::now();
+        let err = wait_for(
+            r#"GetInterface "ift-does-not-exist""#,
+            Duration::from_millis(200),
+            Duration::from_millis(50),
+        )
+        .expect_err("should time out");
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        match err {
+            IfTError::IfTTimeoutError(_) => (),
+            _ => panic!("expected a timeout, got {}", err),
+        }
+    }
+
+    #[test]
+    fn test_wait_for_without_deadline() {
+        let ips = wait_for("GetAllInterfaces", Duration::MAX, Duration::from_millis(50)).unwrap();
+        assert!(!ips.is_empty());
+    }
+
+    #[test]
+    fn test_watch_yields_current_result_first() {
+        let template: Template = "GetAllInterfaces".parse().unwrap();
+        let first = template.watch(Duration::from_millis(50)).next().unwrap().unwrap();
+        assert_eq!(template.eval().unwrap(), first);
+    }
+
+    #[test]
+    fn test_watch_detailed_yields_current_result_first() {
+        let template: Template = "GetAllInterfaces".parse().unwrap();
+        let first = template
+            .watch_detailed(Duration::from_millis(50))
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(template.eval_detailed().unwrap(), first);
+    }
+
+    #[test]
+    fn test_wait_for_bad_template() {
+        wait_for("adoe", Duration::from_secs(5), Duration::from_millis(50)).expect_err("should fail");
+    }
+}
@@ -1,7 +1,31 @@
 #[cfg(test)]
 mod tests {
     use assert_cmd::prelude::*;
-    use std::process::Command;
+    use std::{
+        io::{BufRead, BufReader},
+        process::{Command, Stdio},
+    };
+
+    /// The loopback interface, which has 127.0.0.1 on every os
+    fn lo() -> &'static str {
+        if cfg!(target_os = "macos") {
+            "lo0"
+        } else {
+            "lo"
+        }
+    }
+
+    /// A template that always produces exactly 127.0.0.1
+    fn lo_v4() -> String {
+        format!("GetInterface \"{}\" | FilterIPv4", lo())
+    }
+
+    /// The ift binary, called with `args`
+    fn ift(args: &[&str]) -> Command {
+        let mut cmd = Command::cargo_bin("ift").unwrap();
+        cmd.args(args);
+        cmd
+    }
 
     #[test]
     fn all() {
@@ -17,4 +41,346 @@ mod tests {
             Command::cargo_bin("ift").unwrap().args(&cmd).assert().stdout(stdout);
         }
     }
+
+    #[test]
+    fn eval_formats() {
+        let cmds = vec![
+            ("lines", "127.0.0.1\n"),
+            ("space", "127.0.0.1\n"),
+            ("first", "127.0.0.1\n"),
+            ("json", "[\"127.0.0.1\"]\n"),
+            ("csv", "ip_addr\n127.0.0.1\n"),
+        ];
+        for (format, stdout) in cmds {
+            ift(&["eval", "--format", format, &lo_v4()]).assert().stdout(stdout);
+        }
+        ift(&["eval", "--detailed", "--format", "csv", &lo_v4()])
+            .assert()
+            .stdout(predicates::str::starts_with(format!(
+                "ip_addr,prefix,interface,index,flags,scope,forwardable,global,rfc6890\n127.0.0.1,8,{},",
+                lo()
+            )));
+    }
+
+    #[test]
+    fn eval_exit_codes() {
+        let cmds: Vec<(Vec<&str>, i32)> = vec![
+            (vec!["--require", "1"], 0),
+            (vec!["--exactly", "1"], 0),
+            (vec!["--at-most", "1"], 0),
+            (vec!["--require", "2"], 3),
+            (vec!["--exactly", "0"], 4),
+            (vec!["--at-most", "0"], 4),
+        ];
+        for (args, code) in cmds {
+            ift(&["eval"]).args(&args).arg(lo_v4()).assert().code(code);
+        }
+        ift(&["eval", "GetInterface \"nothere\"", "--require", "1"])
+            .assert()
+            .code(3)
+            .stdout("");
+        ift(&["eval", "GetNothing"]).assert().code(2);
+    }
+
+    #[test]
+    fn rfc_formats() {
+        ift(&["rfc", "6890", "--format", "csv"])
+            .assert()
+            .success()
+            .stdout(predicates::str::starts_with(
+                "address_block,name,rfc,allocation_date,terminated,source,destination,forwardable,global,\
+                 reserved_by_protocol,notes\n0.0.0.0/8,",
+            ));
+        ift(&["rfc", "5771", "--format", "json"])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains(r#""scope": "link-local""#));
+        ift(&["rfc", "6890", "--format", "xml"]).assert().failure();
+    }
+
+    #[test]
+    fn rfc_lookup() {
+        ift(&["rfc", "lookup", "100.64.3.4", "8.8.8.8", "--format", "csv"])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains(
+                "100.64.3.4,true,100.64.0.0/10,Shared Address Space,[RFC6598],,true,true,true,false,false,\n\
+                 8.8.8.8,false,,not special-purpose,,,,,,,,\n",
+            ));
+        ift(&["rfc", "lookup", "192.88.99.1", "--format", "csv"])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains("192.88.99.1,false,,not special-purpose,"));
+        ift(&[
+            "rfc",
+            "lookup",
+            "192.88.99.1",
+            "--include-terminated",
+            "--format",
+            "csv",
+        ])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "192.88.99.1,true,192.88.99.0/24,Deprecated (6to4 Relay Anycast),[RFC7526],2015-03,",
+        ));
+        ift(&["rfc", "lookup", "fe80::/64", "--format", "json"])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains(r#""name": "Link-Local Unicast""#));
+        ift(&["rfc", "lookup", "nope"]).assert().failure();
+    }
+
+    #[test]
+    fn wait() {
+        ift(&["wait", "GetAllInterfaces", "--timeout", "5s", "--format", "first"])
+            .assert()
+            .success();
+        let cmds: Vec<(Vec<&str>, i32)> = vec![
+            (
+                vec!["GetInterface \"nothere\"", "--timeout", "200ms", "--poll", "50ms"],
+                3,
+            ),
+            (vec!["GetAllInterfaces", "--timeout", "200ms", "--at-most", "0"], 4),
+            (vec!["GetNothing", "--timeout", "200ms"], 2),
+            (vec!["GetAllInterfaces", "--timeout", "soon"], 1),
+            (vec!["GetAllInterfaces", "--timeout", "18446744073709551615m"], 1),
+            (vec!["GetAllInterfaces", "--timeout", "18446744073709551615s"], 0),
+        ];
+        for (args, code) in cmds {
+            ift(&["wait"]).args(&args).assert().code(code);
+        }
+    }
+
+    #[test]
+    fn watch() {
+        let mut child = ift(&["watch", &lo_v4()]).stdout(Stdio::piped()).spawn().unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        child.kill().unwrap();
+        assert_eq!("[\"127.0.0.1\"]\n", line);
+
+        ift(&["watch", "GetNothing"]).assert().code(2);
+    }
+
+    #[test]
+    fn lint_errors_still_evaluate() {
+        // parses, but can never match anything, which `check` reports and evaluation does not
+        let template = "GetAllInterfaces | FilterIPv4 | FilterIPv6";
+        ift(&["eval", template]).assert().success().stdout("[]\n");
+        ift(&["wait", "--require", "0", "--timeout", "1s", template])
+            .assert()
+            .success()
+            .stdout("[]\n");
+        let mut child = ift(&["watch", template]).stdout(Stdio::piped()).spawn().unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        child.kill().unwrap();
+        assert_eq!("[]\n", line);
+    }
+
+    #[test]
+    fn render() {
+        ift(&["render"])
+            .with_stdin()
+            .buffer(format!("listen {{{{ {} }}}}:80;\n", lo_v4()))
+            .assert()
+            .success()
+            .stdout("listen 127.0.0.1:80;\n");
+        ift(&["render"])
+            .with_stdin()
+            .buffer("listen {{ GetNothing }}:80;\n")
+            .assert()
+            .code(2);
+    }
+
+    #[test]
+    fn exec() {
+        let env = format!("ADDR={}", lo_v4());
+        ift(&["exec", "--env", &env, "--", "sh", "-c", "echo \"$ADDR\" {{ADDR}}:80"])
+            .assert()
+            .success()
+            .stdout("127.0.0.1 127.0.0.1:80\n");
+        ift(&["exec", "--env", "ADDR=GetInterface \"nothere\"", "--", "true"])
+            .assert()
+            .code(3)
+            .stderr(predicates::str::contains("ADDR"));
+        ift(&["exec", "--env", "ADDR=GetNothing", "--", "true"])
+            .assert()
+            .code(2);
+        ift(&["exec", "--env", &env, "--", "sh", "-c", "exit 7"])
+            .assert()
+            .code(7);
+    }
+
+    #[test]
+    fn eval_sources() {
+        let template = format!("# loopback\nGetInterface \"{}\"\n  | FilterIPv4\n", lo());
+        ift(&["eval", "-"])
+            .with_stdin()
+            .buffer(template.clone())
+            .assert()
+            .stdout("[127.0.0.1]\n");
+        ift(&["eval", "--from-env", "IFT_TEMPLATE"])
+            .env("IFT_TEMPLATE", &template)
+            .assert()
+            .stdout("[127.0.0.1]\n");
+        ift(&["eval", "--from-env", "IFT_NOT_SET"])
+            .env_remove("IFT_NOT_SET")
+            .assert()
+            .code(1);
+        ift(&["eval", "-f", "does-not-exist.ift"]).assert().code(1);
+        ift(&["eval"]).assert().failure();
+    }
+
+    #[test]
+    fn eval_named() {
+        let templates = format!("# addresses\nV4 = {}\nNONE=GetInterface \"nothere\"\n", lo_v4());
+        let cmds = vec![
+            ("space", "V4=127.0.0.1\nNONE=\n"),
+            ("list", "V4=[127.0.0.1]\nNONE=[]\n"),
+            ("json", "{\"NONE\":[],\"V4\":[\"127.0.0.1\"]}\n"),
+            ("csv", "name,ip_addr\nV4,127.0.0.1\n"),
+        ];
+        for (format, stdout) in cmds {
+            ift(&["eval", "--named", "-f", "-", "--format", format])
+                .with_stdin()
+                .buffer(templates.clone())
+                .assert()
+                .stdout(stdout);
+        }
+        ift(&["eval", "--named", "-", "--require", "1"])
+            .with_stdin()
+            .buffer(templates.clone())
+            .assert()
+            .code(3)
+            .stderr(predicates::str::contains("NONE"));
+        ift(&["eval", "--named", "-"])
+            .with_stdin()
+            .buffer("GetAllInterfaces\n")
+            .assert()
+            .code(1);
+    }
+
+    #[test]
+    fn interfaces() {
+        ift(&["interfaces"])
+            .assert()
+            .success()
+            .stdout(predicates::str::starts_with("interface  index  flags"))
+            .stdout(predicates::str::contains("127.0.0.1/8"));
+        ift(&["interfaces", "--json"])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains(format!(r#""name": "{}""#, lo())))
+            .stdout(predicates::str::contains(r#""address": "127.0.0.1""#));
+    }
+
+    #[test]
+    fn explain() {
+        ift(&["explain", &format!("{} | FilterForwardable", lo_v4())])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains(format!(
+                "| FilterForwardable: 1 in, 0 out\n    127.0.0.1 ({}) dropped by FilterForwardable: RFC 6890 Loopback, \
+                 forwardable=false\n    => []\n",
+                lo()
+            )));
+        ift(&["explain", "--json", "GetAllInterfaces | FilterIPv4"])
+            .assert()
+            .success()
+            .stdout(predicates::str::contains(r#""reason": "not an IPv4 address""#))
+            .stdout(predicates::str::contains(r#""scope_id": null"#));
+        ift(&["explain", "GetNothing"]).assert().code(2);
+    }
+
+    #[test]
+    fn check() {
+        ift(&["check", "GetPrivateInterfaces", "GetAllInterfaces | FilterIPv6"])
+            .assert()
+            .success()
+            .stdout("");
+        ift(&[
+            "check",
+            "GetPrivateInterfaces",
+            "GetAllInterfaces | FilterIPv4 | FilterIPv6",
+        ])
+        .assert()
+        .code(2)
+        .stdout(
+            "error: FilterIPv6 after FilterIPv4 never matches anything
+ --> 1:33
+  |
+1 | GetAllInterfaces | FilterIPv4 | FilterIPv6
+  |                                 ^^^^^^^^^^
+",
+        );
+        let redundant = "GetPrivateInterfaces | FilterForwardable";
+        ift(&["check", redundant]).assert().success();
+        ift(&["check", "--strict", redundant]).assert().code(2);
+    }
+
+    #[test]
+    fn without_default_route() {
+        // without a PATH there is no `ip` or `route` to look up the default route with
+        ift(&["eval", &format!(r#"GetInterface "{}" | SortBy "default""#, lo())])
+            .env("PATH", "")
+            .assert()
+            .success()
+            .stdout(predicates::str::contains("127.0.0.1"));
+        ift(&["eval", "GetPrivateInterfaces"])
+            .env("PATH", "")
+            .assert()
+            .success();
+        // polled many times, but only warned about once
+        let output = ift(&[
+            "wait",
+            r#"GetAllInterfaces | SortBy "default" | FilterName "nothere""#,
+            "--timeout",
+            "500ms",
+            "--poll",
+            "50ms",
+        ])
+        .env("PATH", "")
+        .output()
+        .unwrap();
+        assert_eq!(Some(3), output.status.code());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert_eq!(1, stderr.matches("unable to look up the default route").count());
+    }
+
+    #[test]
+    fn eval_zones() {
+        let output = ift(&[
+            "eval",
+            "--format",
+            "lines",
+            r#"GetAllInterfaces | FilterCIDR "fe80::/10""#,
+        ])
+        .output()
+        .unwrap();
+        assert!(output.status.success());
+        for line in String::from_utf8(output.stdout).unwrap().lines() {
+            let (_, zone) = line.split_once('%').expect("link-local addresses have a zone");
+            let template = format!(r#"GetAllInterfaces | FilterCIDR "fe80::/10%{}""#, zone);
+            ift(&["eval", "--format", "lines", &template])
+                .assert()
+                .success()
+                .stdout(predicates::str::contains(line));
+            ift(&["wait", "--format", "lines", "--timeout", "1s", &template])
+                .assert()
+                .success()
+                .stdout(predicates::str::contains(line));
+            ift(&["render"])
+                .with_stdin()
+                .buffer(format!("[{{{{ {} }}}}]:80\n", template))
+                .assert()
+                .success()
+                .stdout(predicates::str::contains(line));
+        }
+    }
 }
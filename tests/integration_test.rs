@@ -1,14 +1,22 @@
 #[cfg(test)]
 mod tests {
-    use ift::{eval, evals};
+    use ift::{
+        eval, eval_socket_addrs, evals,
+        rfc::{WithRfc4291, WithRfc5771, WithRfc6890},
+        BindSpec, DefaultRoutePolicy, IfTError, Interface, IpInterface, Lint, ParseError, Template, TraceStage,
+        Watcher,
+    };
+    use std::{error::Error, net::SocketAddr};
 
     #[test]
     fn all() {
-        assert_eq!(true, evals("GetAllInterfaces | FilterForwardable").is_some());
-        assert_eq!(false, evals("GetAllInterfaces | FilterGlobal").is_some()); // assuming behind router
+        assert_eq!(true, evals("GetAllInterfaces | FilterForwardable").unwrap().is_some());
+        assert_eq!(false, evals("GetAllInterfaces | FilterGlobal").unwrap().is_some()); // assuming behind router
         assert_eq!(
             true,
-            evals("GetAllInterfaces | FilterIPv4 | SortBy \"default\" | FilterFirst").is_some()
+            evals("GetAllInterfaces | FilterIPv4 | SortBy \"default\" | FilterFirst")
+                .unwrap()
+                .is_some()
         )
     }
 
@@ -16,4 +24,69 @@ mod tests {
     fn it_fails() {
         eval("adoe").expect_err("should fail");
     }
+
+    #[test]
+    fn it_is_a_std_error() {
+        let err: Box<dyn Error> = Box::new(eval("GetAllInterfaces | FilterIpv4").unwrap_err());
+        let source = err.source().expect("parse errors have a source");
+        assert!(source.to_string().contains("did you mean `FilterIPv4`?"));
+        match evals(r#"GetAllInterfaces | SortBy "nothing""#) {
+            Err(IfTError::IfTArgumentError(argument)) => assert_eq!("nothing", argument),
+            other => panic!("expected an argument error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_makes_socket_addrs() {
+        let addrs = eval_socket_addrs("GetAllInterfaces", 8080).unwrap();
+        assert_eq!(eval("GetAllInterfaces").unwrap().len(), addrs.len());
+        for addr in addrs {
+            assert_eq!(8080, addr.port());
+            if let SocketAddr::V6(addr) = addr {
+                let link_local = addr.ip().segments()[0] & 0xffc0 == 0xfe80;
+                assert_eq!(link_local, addr.scope_id() != 0, "scope id of {}", addr);
+            }
+        }
+        let addrs = eval_socket_addrs(r#"GetAllInterfaces | WithPort "443" | FilterIPv4"#, 8080).unwrap();
+        assert!(addrs.iter().all(|addr| addr.is_ipv4() && addr.port() == 443));
+        match eval_socket_addrs(r#"GetAllInterfaces | WithPort "http""#, 8080) {
+            Err(IfTError::IfTArgumentError(argument)) => assert_eq!("http", argument),
+            other => panic!("expected an argument error, got {:?}", other),
+        }
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn it_is_send_and_sync() {
+        assert_send_sync::<Template>();
+        assert_send_sync::<BindSpec>();
+        assert_send_sync::<DefaultRoutePolicy>();
+        assert_send_sync::<Watcher>();
+        assert_send_sync::<IpInterface>();
+        assert_send_sync::<Interface>();
+        assert_send_sync::<TraceStage>();
+        assert_send_sync::<Lint>();
+        assert_send_sync::<IfTError>();
+        assert_send_sync::<ParseError>();
+        assert_send_sync::<WithRfc6890>();
+        assert_send_sync::<WithRfc4291>();
+        assert_send_sync::<WithRfc5771>();
+    }
+
+    #[test]
+    fn it_evaluates_on_many_threads() {
+        let template: Template = "GetAllInterfaces | FilterIPv4".parse().unwrap();
+        let expected = template.eval().unwrap();
+        let template = std::sync::Arc::new(template);
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let template = template.clone();
+                std::thread::spawn(move || template.eval().unwrap())
+            })
+            .collect();
+        for thread in threads {
+            assert_eq!(expected, thread.join().unwrap());
+        }
+    }
 }